@@ -0,0 +1,204 @@
+use clap::Parser;
+use riichi_mahjong_scoring_calculator::implements::{
+    calculate_agari_explained,
+    game::{AgariType, GameContext, PlayerContext},
+    hand::{KanType, MentsuType},
+    input::{OpenMeldInput, UserInput},
+    notation::parse_hand,
+    rules::RuleConfig,
+    tiles::{Hai, Jihai, Kaze},
+};
+use std::process::ExitCode;
+
+/// Score a winning hand written in mpsz notation
+#[derive(Parser)]
+#[command(name = "riichi-cli")]
+struct Args {
+    /// concealed tiles including the winning tile, e.g. 123m456p789s11z
+    #[arg(long)]
+    hand: String,
+    /// winning tile, e.g. 1m (default: the last tile of --hand)
+    #[arg(long)]
+    win: Option<String>,
+    /// win by self-draw (default is ron)
+    #[arg(long)]
+    tsumo: bool,
+    /// called sequence, e.g. 345p (repeatable)
+    #[arg(long)]
+    chi: Vec<String>,
+    /// called triplet, e.g. 555z (repeatable)
+    #[arg(long)]
+    pon: Vec<String>,
+    /// open kan, e.g. 1111m (repeatable)
+    #[arg(long)]
+    kan: Vec<String>,
+    /// closed kan, e.g. 9999s (repeatable)
+    #[arg(long)]
+    ankan: Vec<String>,
+    #[arg(long)]
+    riichi: bool,
+    #[arg(long)]
+    double_riichi: bool,
+    #[arg(long)]
+    ippatsu: bool,
+    /// dora indicators, e.g. 2p or 2p5s (none given: dora are not counted)
+    #[arg(long, default_value = "")]
+    dora: String,
+    /// ura dora indicators, one per dora indicator under riichi
+    #[arg(long, default_value = "")]
+    ura: String,
+    /// own discards, flags furiten when a wait is among them
+    #[arg(long, default_value = "")]
+    discards: String,
+    /// number of red fives
+    #[arg(long, default_value_t = 0)]
+    aka: u8,
+    /// seat wind: E, S, W or N
+    #[arg(long, default_value = "E")]
+    seat: String,
+    /// round wind: E, S, W or N
+    #[arg(long, default_value = "E")]
+    round: String,
+    /// hand number within the round, 1-4
+    #[arg(long, default_value_t = 1)]
+    kyoku: u8,
+    #[arg(long, default_value_t = 0)]
+    honba: u8,
+    /// riichi deposits on the table
+    #[arg(long, default_value_t = 0)]
+    sticks: u8,
+    #[arg(long)]
+    rinshan: bool,
+    #[arg(long)]
+    chankan: bool,
+    #[arg(long)]
+    haitei: bool,
+    #[arg(long)]
+    houtei: bool,
+    #[arg(long)]
+    tenhou: bool,
+    #[arg(long)]
+    chiihou: bool,
+    #[arg(long)]
+    renhou: bool,
+    /// print how the score was derived
+    #[arg(long)]
+    explain: bool,
+}
+
+fn parse_wind(notation: &str) -> Result<Kaze, String> {
+    match notation.parse()? {
+        Hai::Jihai(Jihai::Kaze(kaze)) => Ok(kaze),
+        _ => Err(format!(
+            "Expected a wind (E, S, W or N), got '{}'",
+            notation
+        )),
+    }
+}
+
+// a called meld is stored by its lowest tile
+fn parse_meld(notation: &str, mentsu_type: MentsuType) -> Result<OpenMeldInput, &'static str> {
+    let tiles = parse_hand(notation)?;
+    let representative_tile = *tiles.iter().min().ok_or("Empty meld")?;
+    Ok(OpenMeldInput {
+        mentsu_type,
+        representative_tile,
+        kan_type: (mentsu_type == MentsuType::Kantsu).then_some(KanType::Daiminkan),
+        called_tile: None,
+        called_from: None,
+    })
+}
+
+fn build_input(args: &Args) -> Result<UserInput, String> {
+    let agari_type = if args.tsumo {
+        AgariType::Tsumo
+    } else {
+        AgariType::Ron
+    };
+    let mut hand_tiles = parse_hand(&args.hand)?;
+    let winning_tile = match &args.win {
+        Some(win) => Hai::try_from(win.as_str())?,
+        None => *hand_tiles.last().ok_or("Empty hand")?,
+    };
+    if agari_type == AgariType::Ron {
+        let pos = hand_tiles
+            .iter()
+            .position(|t| *t == winning_tile)
+            .ok_or("Winning tile not in hand")?;
+        hand_tiles.remove(pos);
+    }
+
+    let mut open_melds = Vec::new();
+    for (melds, mentsu_type) in [
+        (&args.chi, MentsuType::Shuntsu),
+        (&args.pon, MentsuType::Koutsu),
+        (&args.kan, MentsuType::Kantsu),
+    ] {
+        for meld in melds {
+            open_melds.push(parse_meld(meld, mentsu_type)?);
+        }
+    }
+
+    let closed_kans = args
+        .ankan
+        .iter()
+        .map(|k| parse_meld(k, MentsuType::Kantsu).map(|m| m.representative_tile))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let jikaze = parse_wind(&args.seat)?;
+
+    Ok(UserInput {
+        hand_tiles,
+        winning_tile,
+        player_context: PlayerContext {
+            jikaze,
+            is_oya: jikaze == Kaze::Ton,
+            is_riichi: args.riichi || args.double_riichi,
+            is_daburu_riichi: args.double_riichi,
+            is_ippatsu: args.ippatsu,
+            is_menzen: open_melds.is_empty(),
+            discards: parse_hand(&args.discards)?,
+        },
+        open_melds,
+        closed_kans,
+        game_context: GameContext {
+            bakaze: parse_wind(&args.round)?,
+            kyoku: args.kyoku,
+            honba: args.honba,
+            riichi_sticks: args.sticks,
+            dora_indicators: parse_hand(&args.dora)?,
+            uradora_indicators: parse_hand(&args.ura)?,
+            num_akadora: args.aka,
+            is_tenhou: args.tenhou,
+            is_chiihou: args.chiihou,
+            is_renhou: args.renhou,
+            is_haitei: args.haitei,
+            is_houtei: args.houtei,
+            is_rinshan: args.rinshan,
+            is_chankan: args.chankan,
+            pao_player: None,
+            rules: RuleConfig::default(),
+        },
+        agari_type,
+    })
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    match build_input(&args)
+        .and_then(|input| calculate_agari_explained(&input).map_err(String::from))
+    {
+        Ok((result, trace)) => {
+            println!("{}", result);
+            if args.explain {
+                println!("\n{}", trace);
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -1,9 +1,10 @@
 use super::messages::Message;
 use super::styles::ColoredButtonStyle;
-use crate::implements::tiles::{Hai, Jihai, Kaze, Sangenpai, Suhai, Suit};
+use crate::implements::tiles::{Hai, Jihai, Kaze, Sangenpai, Suhai, Suit, tile_to_index};
 use iced::Element;
 use iced::theme;
-use iced::widget::{button, column, row, text};
+use iced::widget::{button, column, container, row, text};
+use std::collections::HashMap;
 
 #[allow(dead_code)]
 pub trait OnPressMaybe {
@@ -68,34 +69,10 @@ pub fn create_grid(elements: Vec<Element<Message>>, columns: usize) -> Element<M
     rows.into()
 }
 
-pub fn sort_tiles_by_type(tile: &Hai) -> (u8, u8) {
-    match tile {
-        Hai::Suhai(Suhai {
-            number: n,
-            suit: Suit::Manzu,
-        }) => (0, *n),
-        Hai::Suhai(Suhai {
-            number: n,
-            suit: Suit::Pinzu,
-        }) => (1, *n),
-        Hai::Suhai(Suhai {
-            number: n,
-            suit: Suit::Souzu,
-        }) => (2, *n),
-        Hai::Jihai(Jihai::Kaze(Kaze::Ton)) => (3, 0),
-        Hai::Jihai(Jihai::Kaze(Kaze::Nan)) => (3, 1),
-        Hai::Jihai(Jihai::Kaze(Kaze::Shaa)) => (3, 2),
-        Hai::Jihai(Jihai::Kaze(Kaze::Pei)) => (3, 3),
-        Hai::Jihai(Jihai::Sangen(Sangenpai::Haku)) => (4, 0),
-        Hai::Jihai(Jihai::Sangen(Sangenpai::Hatsu)) => (4, 1),
-        Hai::Jihai(Jihai::Sangen(Sangenpai::Chun)) => (4, 2),
-    }
-}
-
 pub fn insert_tile_sorted(tiles: &mut Vec<Hai>, tile: Hai) {
-    let key = sort_tiles_by_type(&tile);
+    let key = tile_to_index(&tile);
     let pos = tiles
-        .binary_search_by_key(&key, sort_tiles_by_type)
+        .binary_search_by_key(&key, tile_to_index)
         .unwrap_or_else(|pos| pos);
     tiles.insert(pos, tile);
 }
@@ -131,19 +108,56 @@ pub fn cancel_button<'a>() -> Element<'a, Message> {
     )
 }
 
+pub type TileImages = HashMap<Hai, iced::widget::image::Handle>;
+
+// short label used when a tile image is missing, matches parse_hand notation
+pub fn tile_label(tile: &Hai) -> String {
+    match tile {
+        Hai::Suhai(Suhai { number, suit }) => {
+            let suit_char = match suit {
+                Suit::Manzu => 'm',
+                Suit::Pinzu => 'p',
+                Suit::Souzu => 's',
+            };
+            format!("{}{}", number, suit_char)
+        }
+        Hai::Jihai(jihai) => match jihai {
+            Jihai::Kaze(Kaze::Ton) => "E",
+            Jihai::Kaze(Kaze::Nan) => "S",
+            Jihai::Kaze(Kaze::Shaa) => "W",
+            Jihai::Kaze(Kaze::Pei) => "N",
+            Jihai::Sangen(Sangenpai::Haku) => "H",
+            Jihai::Sangen(Sangenpai::Hatsu) => "G",
+            Jihai::Sangen(Sangenpai::Chun) => "R",
+        }
+        .to_string(),
+    }
+}
+
 pub fn tile_image_button<'a>(
-    handle: iced::widget::image::Handle,
+    images: &TileImages,
+    tile: &Hai,
     width: u16,
     msg: Message,
     style: theme::Button,
 ) -> Element<'a, Message> {
-    button(iced::widget::Image::new(handle).width(width))
+    button(tile_image(images, tile, width))
         .on_press(msg)
         .style(style)
         .padding(0)
         .into()
 }
 
-pub fn tile_image<'a>(handle: iced::widget::image::Handle, width: u16) -> Element<'a, Message> {
-    iced::widget::Image::new(handle).width(width).into()
+// falls back to a text label when the asset failed to load
+pub fn tile_image<'a>(images: &TileImages, tile: &Hai, width: u16) -> Element<'a, Message> {
+    match images.get(tile) {
+        Some(handle) => iced::widget::Image::new(handle.clone()).width(width).into(),
+        None => container(text(tile_label(tile)).size(width / 2))
+            .width(width)
+            .height(width * 4 / 3)
+            .center_x()
+            .center_y()
+            .style(theme::Container::Box)
+            .into(),
+    }
 }
@@ -1,5 +1,5 @@
 use crate::implements::game::AgariType;
-use crate::implements::hand::MentsuType;
+use crate::implements::hand::{KanType, MentsuType};
 use crate::implements::input::OpenMeldInput;
 use crate::implements::tiles::{Hai, Kaze};
 
@@ -8,8 +8,12 @@ pub enum Message {
     // --- Composition Phase ---
     AddTile(Hai),
     RemoveTile(usize),
+    HandChanged,
+    KeyPressed(char),
     ConfirmHand,
     CancelSelection,
+    #[cfg(feature = "rand")]
+    RandomHand,
 
     // --- Definition Phase ---
     ModifyHand,
@@ -17,6 +21,7 @@ pub enum Message {
     SelectWinningTile(Hai),
     SelectMeldType(MentsuType),
     SelectCompleteMeld(OpenMeldInput),
+    StartAddOpenKan(KanType),
     StartAddClosedKan,
     SelectClosedKan(Hai),
     RemoveOpenMeld(usize),
@@ -25,7 +30,8 @@ pub enum Message {
     DecrementAkadora,
     ToggleAgariType(AgariType),
     SetBakaze(Kaze),
-    SetJikaze(Kaze),
+    SetKyoku(u8),
+    SetSeat(u8),
     ToggleRiichi(bool),
     ToggleDoubleRiichi(bool),
     ToggleIppatsu(bool),
@@ -38,6 +44,8 @@ pub enum Message {
     ToggleRenhou(bool),
     IncrementHonba,
     DecrementHonba,
+    IncrementRiichiSticks,
+    DecrementRiichiSticks,
     StartAddDora,
     SelectDora(Hai),
     StartAddUraDora,
@@ -9,38 +9,58 @@ use self::messages::Message;
 use self::state::RiichiGui;
 use self::update::Update;
 use self::view::View;
-use iced::{Element, Sandbox, Settings};
+use iced::keyboard::{self, Key, key::Named};
+use iced::{Application, Command, Element, Settings, Subscription, Theme, executor};
 
 pub fn run() -> iced::Result {
-    let mut settings = Settings::default();
-    settings.fonts = vec![
-        include_bytes!("../../assets/font/Arimo.ttf")
-            .as_slice()
-            .into(),
-        include_bytes!("../../assets/font/Arimo-Bold.ttf")
-            .as_slice()
-            .into(),
-    ];
-    settings.default_font = iced::Font::with_name("Arimo");
+    let settings = Settings {
+        fonts: vec![
+            include_bytes!("../../assets/font/Arimo.ttf")
+                .as_slice()
+                .into(),
+            include_bytes!("../../assets/font/Arimo-Bold.ttf")
+                .as_slice()
+                .into(),
+        ],
+        default_font: iced::Font::with_name("Arimo"),
+        ..Settings::default()
+    };
     RiichiGui::run(settings)
 }
 
-impl Sandbox for RiichiGui {
+impl Application for RiichiGui {
+    type Executor = executor::Default;
     type Message = Message;
+    type Theme = Theme;
+    type Flags = ();
 
-    fn new() -> Self {
-        Self::new()
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (Self::new(), Command::none())
     }
 
     fn title(&self) -> String {
         String::from("Riichi Mahjong Scoring Calculator")
     }
 
-    fn update(&mut self, message: Message) {
-        Update::update(self, message)
+    fn update(&mut self, message: Message) -> Command<Message> {
+        Update::update(self, message);
+        Command::none()
     }
 
     fn view(&self) -> Element<'_, Message> {
         View::view(self)
     }
+
+    // keyboard tile entry
+    fn subscription(&self) -> Subscription<Message> {
+        keyboard::on_key_press(key_to_message)
+    }
+}
+
+fn key_to_message(key: Key, _modifiers: keyboard::Modifiers) -> Option<Message> {
+    match key.as_ref() {
+        Key::Character(s) => s.chars().next().map(Message::KeyPressed),
+        Key::Named(Named::Backspace) => Some(Message::KeyPressed('\u{8}')),
+        _ => None,
+    }
 }
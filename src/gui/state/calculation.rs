@@ -1,5 +1,10 @@
 use super::RiichiGui;
-use crate::implements::types::tiles::{Hai, Suhai};
+use crate::implements::types::{
+    game::{AgariType, GameContext, PlayerContext, seat_wind},
+    input::UserInput,
+    rules::RuleConfig,
+    tiles::{Hai, Kaze, Suhai, tile_to_index},
+};
 
 impl RiichiGui {
     pub fn get_max_akadora_count(&self) -> u8 {
@@ -17,31 +22,108 @@ impl RiichiGui {
             }
         };
 
+        // hand_tiles already holds meld, kan and winning tiles
         for tile in &self.hand_tiles {
             check_tile(tile, &mut count_5m, &mut count_5p, &mut count_5s);
         }
 
-        if let Some(tile) = &self.winning_tile {
-            check_tile(tile, &mut count_5m, &mut count_5p, &mut count_5s);
+        // 1 red 5-man, 2 red 5-pin, 1 red 5-sou
+        let max_m = if count_5m > 0 { 1 } else { 0 };
+        let max_p = if count_5p >= 2 { 2 } else { count_5p };
+        let max_s = if count_5s > 0 { 1 } else { 0 };
+
+        max_m + max_p + max_s
+    }
+
+    // re-derives jikaze after a kyoku or seat change
+    pub fn update_seat_wind(&mut self) {
+        self.jikaze = seat_wind(self.kyoku, self.seat);
+        // Reset Tenhou/Chiihou
+        if self.jikaze != Kaze::Ton {
+            self.is_tenhou = false;
+        } else {
+            self.is_chiihou = false;
         }
+    }
 
+    // copies still free after the hand and the dora/ura dora indicators
+    pub fn remaining_tile_count(&self, tile: &Hai) -> u8 {
+        let indicators = self
+            .dora_indicators
+            .iter()
+            .chain(&self.uradora_indicators)
+            .filter(|t| *t == tile)
+            .count() as u8;
+        self.tile_counts[tile_to_index(tile)].saturating_sub(indicators)
+    }
+
+    // engine input from the GUI state, None until a winning tile is chosen
+    pub fn build_user_input(&self) -> Option<UserInput> {
+        let winning_tile = self.winning_tile?;
+        let mut hand_tiles = self.hand_tiles.clone();
+
+        // Filter out Open Melds
         for meld in &self.open_melds {
             for tile in self.get_meld_tiles(meld) {
-                check_tile(&tile, &mut count_5m, &mut count_5p, &mut count_5s);
+                if let Some(pos) = hand_tiles.iter().position(|x| *x == tile) {
+                    hand_tiles.remove(pos);
+                }
             }
         }
 
+        // Filter out Closed Kans
         for tile in &self.closed_kans {
             for _ in 0..4 {
-                check_tile(tile, &mut count_5m, &mut count_5p, &mut count_5s);
+                if let Some(pos) = hand_tiles.iter().position(|x| *x == *tile) {
+                    hand_tiles.remove(pos);
+                }
             }
         }
 
-        // 1 red 5-man, 2 red 5-pin, 1 red 5-sou
-        let max_m = if count_5m > 0 { 1 } else { 0 };
-        let max_p = if count_5p >= 2 { 2 } else { count_5p };
-        let max_s = if count_5s > 0 { 1 } else { 0 };
+        // Remove winning tile if Ron
+        if self.agari_type == AgariType::Ron
+            && let Some(pos) = hand_tiles.iter().position(|x| *x == winning_tile)
+        {
+            hand_tiles.remove(pos);
+        }
 
-        max_m + max_p + max_s
+        Some(UserInput {
+            hand_tiles,
+            open_melds: self.open_melds.clone(),
+            closed_kans: self.closed_kans.clone(),
+            winning_tile,
+            agari_type: self.agari_type,
+            player_context: PlayerContext {
+                jikaze: self.jikaze,
+                is_oya: self.jikaze == Kaze::Ton,
+                is_riichi: self.is_riichi,
+                is_daburu_riichi: self.is_daburu_riichi,
+                is_ippatsu: self.is_ippatsu,
+                is_menzen: self.open_melds.is_empty(),
+                discards: Vec::new(),
+            },
+            game_context: GameContext {
+                bakaze: self.bakaze,
+                kyoku: self.kyoku,
+                honba: self.honba,
+                riichi_sticks: self.riichi_sticks,
+                dora_indicators: self.dora_indicators.clone(),
+                uradora_indicators: self.uradora_indicators.clone(),
+                num_akadora: self.num_akadora,
+                is_tenhou: self.is_tenhou,
+                is_chiihou: self.is_chiihou,
+                is_renhou: self.is_renhou,
+                is_haitei: self.is_haitei,
+                is_houtei: self.is_houtei,
+                is_rinshan: self.is_rinshan,
+                is_chankan: self.is_chankan,
+                pao_player: None,
+                // dora entry is optional in the GUI
+                rules: RuleConfig {
+                    lenient_dora_count: true,
+                    ..Default::default()
+                },
+            },
+        })
     }
 }
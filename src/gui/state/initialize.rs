@@ -10,12 +10,17 @@ use crate::implements::types::{
 struct GameStateDefaults {
     phase: Phase,
     hand_tiles: Vec<Hai>,
+    key_buffer: String,
+    shanten: Option<i8>,
+    advancing_tiles: Vec<Hai>,
     winning_tile: Option<Hai>,
     open_melds: Vec<OpenMeldInput>,
     closed_kans: Vec<Hai>,
     agari_type: AgariType,
     bakaze: Kaze,
     jikaze: Kaze,
+    kyoku: u8,
+    seat: u8,
     is_riichi: bool,
     is_daburu_riichi: bool,
     is_ippatsu: bool,
@@ -27,21 +32,29 @@ struct GameStateDefaults {
     is_chiihou: bool,
     is_renhou: bool,
     honba: u8,
+    riichi_sticks: u8,
     num_akadora: u8,
     dora_indicators: Vec<Hai>,
     uradora_indicators: Vec<Hai>,
+    input_error: Option<&'static str>,
     score_result: Option<Result<crate::implements::types::scoring::AgariResult, String>>,
     show_rules: bool,
 }
 
+impl GameStateDefaults {
+    // East 1, player 0 dealing
+    fn new() -> Self {
+        Self {
+            kyoku: 1,
+            ..Self::default()
+        }
+    }
+}
+
 impl RiichiGui {
     pub fn new() -> Self {
-        let mut tile_images: std::collections::HashMap<
-            crate::implements::types::tiles::Hai,
-            iced::widget::image::Handle,
-        > = std::collections::HashMap::new();
-        for i in 0..34 {
-            let tile = crate::implements::types::tiles::index_to_tile(i);
+        let mut tile_images = crate::gui::components::TileImages::new();
+        for tile in Hai::all() {
             let path = crate::gui::components::get_tile_image_path(&tile);
 
             // fast rendering
@@ -72,18 +85,23 @@ impl RiichiGui {
             None
         };
 
-        let defaults = GameStateDefaults::default();
+        let defaults = GameStateDefaults::new();
 
         Self {
             phase: defaults.phase,
             hand_tiles: defaults.hand_tiles,
             tile_counts: [4; 34],
+            key_buffer: defaults.key_buffer,
+            shanten: defaults.shanten,
+            advancing_tiles: defaults.advancing_tiles,
             winning_tile: defaults.winning_tile,
             open_melds: defaults.open_melds,
             closed_kans: defaults.closed_kans,
             agari_type: defaults.agari_type,
             bakaze: defaults.bakaze,
             jikaze: defaults.jikaze,
+            kyoku: defaults.kyoku,
+            seat: defaults.seat,
             is_riichi: defaults.is_riichi,
             is_daburu_riichi: defaults.is_daburu_riichi,
             is_ippatsu: defaults.is_ippatsu,
@@ -95,9 +113,11 @@ impl RiichiGui {
             is_chiihou: defaults.is_chiihou,
             is_renhou: defaults.is_renhou,
             honba: defaults.honba,
+            riichi_sticks: defaults.riichi_sticks,
             num_akadora: defaults.num_akadora,
             dora_indicators: defaults.dora_indicators,
             uradora_indicators: defaults.uradora_indicators,
+            input_error: defaults.input_error,
             score_result: defaults.score_result,
             show_rules: defaults.show_rules,
             tile_images,
@@ -106,16 +126,21 @@ impl RiichiGui {
     }
 
     pub fn reset(&mut self) {
-        let defaults = GameStateDefaults::default();
+        let defaults = GameStateDefaults::new();
         self.phase = defaults.phase;
         self.hand_tiles = defaults.hand_tiles;
         self.tile_counts = [4; 34];
+        self.key_buffer = defaults.key_buffer;
+        self.shanten = defaults.shanten;
+        self.advancing_tiles = defaults.advancing_tiles;
         self.winning_tile = defaults.winning_tile;
         self.open_melds = defaults.open_melds;
         self.closed_kans = defaults.closed_kans;
         self.agari_type = defaults.agari_type;
         self.bakaze = defaults.bakaze;
         self.jikaze = defaults.jikaze;
+        self.kyoku = defaults.kyoku;
+        self.seat = defaults.seat;
         self.is_riichi = defaults.is_riichi;
         self.is_daburu_riichi = defaults.is_daburu_riichi;
         self.is_ippatsu = defaults.is_ippatsu;
@@ -127,10 +152,60 @@ impl RiichiGui {
         self.is_chiihou = defaults.is_chiihou;
         self.is_renhou = defaults.is_renhou;
         self.honba = defaults.honba;
+        self.riichi_sticks = defaults.riichi_sticks;
         self.num_akadora = defaults.num_akadora;
         self.dora_indicators = defaults.dora_indicators;
         self.uradora_indicators = defaults.uradora_indicators;
+        self.input_error = defaults.input_error;
         self.score_result = defaults.score_result;
         self.show_rules = defaults.show_rules;
     }
+
+    /// Fills the GUI state from a complete input and jumps to Definition
+    #[cfg(feature = "rand")]
+    pub fn load_input(&mut self, input: &crate::implements::types::input::UserInput) {
+        self.reset();
+
+        let mut hand_tiles = input.hand_tiles.clone();
+        if input.agari_type == AgariType::Ron {
+            hand_tiles.push(input.winning_tile);
+        }
+        for meld in &input.open_melds {
+            hand_tiles.extend(self.get_meld_tiles(meld));
+        }
+        for tile in &input.closed_kans {
+            hand_tiles.extend([*tile; 4]);
+        }
+        crate::implements::types::tiles::sort_tiles(&mut hand_tiles);
+
+        for tile in &hand_tiles {
+            self.tile_counts[crate::implements::types::tiles::tile_to_index(tile)] -= 1;
+        }
+
+        let player = &input.player_context;
+        let game = &input.game_context;
+
+        self.hand_tiles = hand_tiles;
+        self.winning_tile = Some(input.winning_tile);
+        self.open_melds = input.open_melds.clone();
+        self.closed_kans = input.closed_kans.clone();
+        self.agari_type = input.agari_type;
+        self.bakaze = game.bakaze;
+        self.jikaze = player.jikaze;
+        self.kyoku = game.kyoku;
+        self.seat = (0..4)
+            .find(|&seat| {
+                crate::implements::types::game::seat_wind(game.kyoku, seat) == player.jikaze
+            })
+            .unwrap_or_default();
+        self.is_riichi = player.is_riichi;
+        self.is_daburu_riichi = player.is_daburu_riichi;
+        self.is_ippatsu = player.is_ippatsu;
+        self.honba = game.honba;
+        self.riichi_sticks = game.riichi_sticks;
+        self.num_akadora = game.num_akadora;
+        self.dora_indicators = game.dora_indicators.clone();
+        self.uradora_indicators = game.uradora_indicators.clone();
+        self.phase = Phase::Definition;
+    }
 }
@@ -1,20 +1,23 @@
 use super::RiichiGui;
-use crate::implements::types::{hand::MentsuType, input::OpenMeldInput, tiles::Hai};
+use crate::implements::types::{
+    hand::{KanType, MentsuType},
+    input::OpenMeldInput,
+    tiles::{Hai, index_to_tile, tile_to_index},
+};
 
 impl RiichiGui {
     pub fn get_meld_tiles(&self, meld: &OpenMeldInput) -> Vec<Hai> {
         let mut tiles = Vec::new();
         match meld.mentsu_type {
             MentsuType::Shuntsu => {
-                let start_idx =
-                    crate::implements::types::tiles::tile_to_index(&meld.representative_tile);
+                let start_idx = tile_to_index(&meld.representative_tile);
                 if start_idx < 27 {
                     let suit_base = (start_idx / 9) * 9;
 
                     for i in 0..3 {
                         let idx = start_idx + i;
                         if idx < suit_base + 9 {
-                            tiles.push(crate::implements::types::tiles::index_to_tile(idx));
+                            tiles.push(index_to_tile(idx));
                         }
                     }
                 } else {
@@ -35,78 +38,54 @@ impl RiichiGui {
         tiles
     }
 
-    /// Checks for meld possibility
-    pub fn can_form_meld(&self, meld: &OpenMeldInput) -> bool {
-        let mut hand_counts = [0u8; 34];
+    /// Counts of hand tiles not yet used by an open meld or closed kan
+    fn available_counts(&self) -> [u8; 34] {
+        let mut available_counts = [0u8; 34];
         for tile in &self.hand_tiles {
-            hand_counts[crate::implements::types::tiles::tile_to_index(tile)] += 1;
+            available_counts[tile_to_index(tile)] += 1;
         }
 
-        for existing_meld in &self.open_melds {
-            for tile in self.get_meld_tiles(existing_meld) {
-                let idx = crate::implements::types::tiles::tile_to_index(&tile);
-                if hand_counts[idx] > 0 {
-                    hand_counts[idx] -= 1;
-                } else {
-                    return false;
-                }
-            }
+        let declared = self
+            .open_melds
+            .iter()
+            .flat_map(|meld| self.get_meld_tiles(meld))
+            .chain(self.closed_kans.iter().flat_map(|tile| [*tile; 4]));
+        for tile in declared {
+            let idx = tile_to_index(&tile);
+            available_counts[idx] = available_counts[idx].saturating_sub(1);
         }
+        available_counts
+    }
 
+    /// Checks for meld possibility
+    pub fn can_form_meld(&self, meld: &OpenMeldInput) -> bool {
+        let mut available_counts = self.available_counts();
         for tile in self.get_meld_tiles(meld) {
-            let idx = crate::implements::types::tiles::tile_to_index(&tile);
-            if hand_counts[idx] > 0 {
-                hand_counts[idx] -= 1;
-            } else {
+            let idx = tile_to_index(&tile);
+            if available_counts[idx] == 0 {
                 return false;
             }
+            available_counts[idx] -= 1;
         }
-
         true
     }
 
-    pub fn get_all_possible_pons(&self) -> Vec<OpenMeldInput> {
-        let mut available_counts = [0u8; 34];
-        for tile in &self.hand_tiles {
-            available_counts[crate::implements::types::tiles::tile_to_index(tile)] += 1;
-        }
-
-        for existing_meld in &self.open_melds {
-            for tile in self.get_meld_tiles(existing_meld) {
-                let idx = crate::implements::types::tiles::tile_to_index(&tile);
-                if available_counts[idx] > 0 {
-                    available_counts[idx] -= 1;
-                }
-            }
-        }
+    pub fn can_form_closed_kan(&self, tile: &Hai) -> bool {
+        self.available_counts()[tile_to_index(tile)] == 4
+    }
 
+    pub fn get_all_possible_pons(&self) -> Vec<OpenMeldInput> {
         let mut pons = Vec::new();
-        for i in 0..34 {
-            if available_counts[i] >= 3 {
-                let tile = crate::implements::types::tiles::index_to_tile(i);
-                pons.push(OpenMeldInput {
-                    mentsu_type: MentsuType::Koutsu,
-                    representative_tile: tile,
-                });
+        for (i, &count) in self.available_counts().iter().enumerate() {
+            if count >= 3 {
+                pons.push(OpenMeldInput::pon(index_to_tile(i)));
             }
         }
         pons
     }
 
     pub fn get_all_possible_chiis(&self) -> Vec<OpenMeldInput> {
-        let mut available_counts = [0u8; 34];
-        for tile in &self.hand_tiles {
-            available_counts[crate::implements::types::tiles::tile_to_index(tile)] += 1;
-        }
-
-        for existing_meld in &self.open_melds {
-            for tile in self.get_meld_tiles(existing_meld) {
-                let idx = crate::implements::types::tiles::tile_to_index(&tile);
-                if available_counts[idx] > 0 {
-                    available_counts[idx] -= 1;
-                }
-            }
-        }
+        let available_counts = self.available_counts();
 
         let mut chiis = Vec::new();
         for suit_offset in [0, 9, 18] {
@@ -119,28 +98,28 @@ impl RiichiGui {
                     && available_counts[idx2] > 0
                     && available_counts[idx3] > 0
                 {
-                    let tile = crate::implements::types::tiles::index_to_tile(idx1);
-                    chiis.push(OpenMeldInput {
-                        mentsu_type: MentsuType::Shuntsu,
-                        representative_tile: tile,
-                    });
+                    chiis.push(OpenMeldInput::chi(index_to_tile(idx1)));
                 }
             }
         }
         chiis
     }
 
-    pub fn get_all_possible_kans(&self) -> Vec<Hai> {
-        let mut available_counts = [0u8; 34];
-        for tile in &self.hand_tiles {
-            available_counts[crate::implements::types::tiles::tile_to_index(tile)] += 1;
+    pub fn get_all_possible_open_kans(&self, kan_type: KanType) -> Vec<OpenMeldInput> {
+        let mut kans = Vec::new();
+        for (i, &count) in self.available_counts().iter().enumerate() {
+            if count == 4 {
+                kans.push(OpenMeldInput::kan(index_to_tile(i), kan_type));
+            }
         }
+        kans
+    }
 
+    pub fn get_all_possible_kans(&self) -> Vec<Hai> {
         let mut kans = Vec::new();
-        for i in 0..34 {
-            if available_counts[i] == 4 {
-                let tile = crate::implements::types::tiles::index_to_tile(i);
-                kans.push(tile);
+        for (i, &count) in self.available_counts().iter().enumerate() {
+            if count == 4 {
+                kans.push(index_to_tile(i));
             }
         }
         kans
@@ -3,6 +3,7 @@ pub mod initialize;
 pub mod melds;
 pub mod phase;
 
+use crate::gui::components::TileImages;
 use crate::implements::types::{
     game::AgariType,
     input::OpenMeldInput,
@@ -16,6 +17,9 @@ pub struct RiichiGui {
     // --- Composition Phase ---
     pub hand_tiles: Vec<Hai>,
     pub tile_counts: [u8; 34],
+    pub key_buffer: String,  // digits typed before a suit letter
+    pub shanten: Option<i8>, // cached on HandChanged, None for 0, 3, 6, 9, 12 or 15+ tiles
+    pub advancing_tiles: Vec<Hai>,
 
     // --- Definition Phase ---
     pub winning_tile: Option<Hai>,
@@ -25,7 +29,9 @@ pub struct RiichiGui {
     // --- Result Phase ---
     pub agari_type: AgariType,
     pub bakaze: Kaze,
-    pub jikaze: Kaze,
+    pub jikaze: Kaze, // derived from kyoku and seat
+    pub kyoku: u8,
+    pub seat: u8, // 0 deals East 1
     pub is_riichi: bool,
     pub is_daburu_riichi: bool,
     pub is_ippatsu: bool,
@@ -37,11 +43,13 @@ pub struct RiichiGui {
     pub is_chiihou: bool,
     pub is_renhou: bool,
     pub honba: u8,
+    pub riichi_sticks: u8,
     pub num_akadora: u8,
     pub dora_indicators: Vec<Hai>,
     pub uradora_indicators: Vec<Hai>,
+    pub input_error: Option<&'static str>, // from validate, refreshed on every change
     pub score_result: Option<Result<crate::implements::types::scoring::AgariResult, String>>,
     pub show_rules: bool,
-    pub tile_images: std::collections::HashMap<Hai, iced::widget::image::Handle>,
+    pub tile_images: TileImages,
     pub rules_image: Option<iced::widget::image::Handle>,
 }
@@ -1,19 +1,15 @@
-use crate::implements::types::hand::MentsuType;
+use crate::implements::types::hand::{KanType, MentsuType};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum Phase {
+    #[default]
     Composition,
     Definition,
     SelectingWinningTile,
     SelectingMeldTile(MentsuType),
+    SelectingOpenKan(KanType),
     SelectingClosedKan,
     SelectingDora,
     SelectingUraDora,
     Result,
 }
-
-impl Default for Phase {
-    fn default() -> Self {
-        Phase::Composition
-    }
-}
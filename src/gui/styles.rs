@@ -77,3 +77,22 @@ impl iced::widget::container::StyleSheet for OverlayStyle {
         }
     }
 }
+
+// gold frame around hand tiles that counted as dora
+pub struct HighlightStyle;
+
+impl iced::widget::container::StyleSheet for HighlightStyle {
+    type Style = iced::Theme;
+
+    fn appearance(&self, _style: &Self::Style) -> iced::widget::container::Appearance {
+        iced::widget::container::Appearance {
+            background: Some(iced::Background::Color(Color::from_rgb(1.0, 0.95, 0.7))),
+            border: iced::Border {
+                color: Color::from_rgb(0.85, 0.65, 0.1),
+                width: 2.0,
+                radius: 4.0.into(),
+            },
+            ..Default::default()
+        }
+    }
+}
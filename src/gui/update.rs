@@ -1,10 +1,11 @@
-use super::components::{insert_tile_sorted, sort_tiles_by_type};
+use super::components::insert_tile_sorted;
 use super::messages::Message;
 use super::state::{Phase, RiichiGui};
-use crate::implements::calculate_agari;
-use crate::implements::game::{AgariType, GameContext, PlayerContext};
-use crate::implements::input::UserInput;
-use crate::implements::tiles::Kaze;
+use crate::implements::game::AgariType;
+use crate::implements::notation::parse_hand;
+use crate::implements::tenpai::{advancing_tiles, shanten};
+use crate::implements::tiles::sort_tiles;
+use crate::implements::{calculate_agari, validate};
 
 pub trait Update {
     fn update(&mut self, message: Message);
@@ -17,9 +18,10 @@ impl Update for RiichiGui {
             Message::AddTile(tile) => {
                 if self.hand_tiles.len() < 18 {
                     let idx = crate::implements::tiles::tile_to_index(&tile);
-                    if self.tile_counts[idx] > 0 {
+                    if self.remaining_tile_count(&tile) > 0 {
                         self.tile_counts[idx] -= 1;
                         insert_tile_sorted(&mut self.hand_tiles, tile);
+                        self.update(Message::HandChanged);
                     }
                 }
             }
@@ -29,6 +31,31 @@ impl Update for RiichiGui {
                     let tile = self.hand_tiles.remove(index);
                     let idx = crate::implements::tiles::tile_to_index(&tile);
                     self.tile_counts[idx] += 1;
+                    self.update(Message::HandChanged);
+                }
+            }
+            Message::HandChanged => {
+                self.shanten = shanten(&self.hand_tiles);
+                self.advancing_tiles = advancing_tiles(&self.hand_tiles);
+            }
+            Message::KeyPressed(c) => {
+                if self.phase != Phase::Composition {
+                    return;
+                }
+                if c == '\u{8}' {
+                    self.key_buffer.pop();
+                } else if c.is_ascii_digit() {
+                    self.key_buffer.push(c);
+                } else if c.is_ascii_alphabetic() {
+                    // "5" + "p" -> 5p, a bare letter is an honor
+                    self.key_buffer.push(c);
+                    let parsed = parse_hand(&self.key_buffer);
+                    self.key_buffer.clear();
+                    if let Ok(tiles) = parsed {
+                        for tile in tiles {
+                            self.update(Message::AddTile(tile));
+                        }
+                    }
                 }
             }
             Message::ConfirmHand => {
@@ -40,12 +67,18 @@ impl Update for RiichiGui {
             Message::CancelSelection => {
                 self.phase = Phase::Definition;
             }
+            #[cfg(feature = "rand")]
+            Message::RandomHand => {
+                let input = crate::implements::random_winning_hand(&mut rand::thread_rng());
+                self.load_input(&input);
+                self.update(Message::HandChanged);
+            }
 
             // --- Definition Phase ---
             Message::ModifyHand => {
                 self.open_melds.clear();
                 self.closed_kans.clear();
-                self.hand_tiles.sort_by_key(sort_tiles_by_type);
+                sort_tiles(&mut self.hand_tiles);
 
                 self.phase = Phase::Composition;
                 self.winning_tile = None;
@@ -65,41 +98,46 @@ impl Update for RiichiGui {
             }
             Message::SelectCompleteMeld(meld) => {
                 // Add a meld
-                if let Phase::SelectingMeldTile(_) = self.phase {
-                    if self.can_form_meld(&meld) {
-                        self.open_melds.push(meld);
+                if let Phase::SelectingMeldTile(_) | Phase::SelectingOpenKan(_) = self.phase
+                    && self.can_form_meld(&meld)
+                {
+                    self.open_melds.push(meld);
 
-                        // Reset if open hand
-                        if !self.open_melds.is_empty() {
-                            self.is_riichi = false;
-                            self.is_daburu_riichi = false;
-                            self.is_ippatsu = false;
-                            self.is_tenhou = false;
-                            self.is_chiihou = false;
-                            self.is_renhou = false;
-                        }
+                    // Reset if open hand
+                    if !self.open_melds.is_empty() {
+                        self.is_riichi = false;
+                        self.is_daburu_riichi = false;
+                        self.is_ippatsu = false;
+                        self.is_tenhou = false;
+                        self.is_chiihou = false;
+                        self.is_renhou = false;
                     }
                 }
                 self.phase = Phase::Definition;
             }
+            Message::StartAddOpenKan(kan_type) => {
+                self.phase = Phase::SelectingOpenKan(kan_type);
+            }
             Message::StartAddClosedKan => {
                 self.phase = Phase::SelectingClosedKan;
             }
             Message::SelectClosedKan(tile) => {
-                self.closed_kans.push(tile);
+                if self.can_form_closed_kan(&tile) {
+                    self.closed_kans.push(tile);
+                }
                 self.phase = Phase::Definition;
             }
 
             Message::RemoveOpenMeld(idx) => {
                 if idx < self.open_melds.len() {
                     let _meld = self.open_melds.remove(idx);
-                    self.hand_tiles.sort_by_key(sort_tiles_by_type);
+                    sort_tiles(&mut self.hand_tiles);
                 }
             }
             Message::RemoveClosedKan(idx) => {
                 if idx < self.closed_kans.len() {
                     let _tile = self.closed_kans.remove(idx);
-                    self.hand_tiles.sort_by_key(sort_tiles_by_type);
+                    sort_tiles(&mut self.hand_tiles);
                 }
             }
 
@@ -124,14 +162,13 @@ impl Update for RiichiGui {
             Message::SetBakaze(kaze) => {
                 self.bakaze = kaze;
             }
-            Message::SetJikaze(kaze) => {
-                self.jikaze = kaze;
-                // Reset Tenhou/Chiihou
-                if self.jikaze != Kaze::Ton {
-                    self.is_tenhou = false;
-                } else {
-                    self.is_chiihou = false;
-                }
+            Message::SetKyoku(kyoku) => {
+                self.kyoku = kyoku;
+                self.update_seat_wind();
+            }
+            Message::SetSeat(seat) => {
+                self.seat = seat;
+                self.update_seat_wind();
             }
             Message::ToggleRiichi(is_riichi) => {
                 self.is_riichi = is_riichi;
@@ -149,12 +186,37 @@ impl Update for RiichiGui {
             Message::ToggleIppatsu(is_ippatsu) => {
                 if self.is_riichi {
                     self.is_ippatsu = is_ippatsu;
+                    if is_ippatsu {
+                        self.is_rinshan = false;
+                    }
+                }
+            }
+            Message::ToggleRinshan(val) => {
+                self.is_rinshan = val;
+                // own kan breaks ippatsu
+                if val {
+                    self.is_ippatsu = false;
+                    self.is_haitei = false;
+                }
+            }
+            Message::ToggleChankan(val) => {
+                self.is_chankan = val;
+                if val {
+                    self.is_houtei = false;
+                }
+            }
+            Message::ToggleHaitei(val) => {
+                self.is_haitei = val;
+                if val {
+                    self.is_rinshan = false;
+                }
+            }
+            Message::ToggleHoutei(val) => {
+                self.is_houtei = val;
+                if val {
+                    self.is_chankan = false;
                 }
             }
-            Message::ToggleRinshan(val) => self.is_rinshan = val,
-            Message::ToggleChankan(val) => self.is_chankan = val,
-            Message::ToggleHaitei(val) => self.is_haitei = val,
-            Message::ToggleHoutei(val) => self.is_houtei = val,
             Message::ToggleTenhou(val) => {
                 if self.open_melds.is_empty() {
                     self.is_tenhou = val;
@@ -176,14 +238,24 @@ impl Update for RiichiGui {
                     self.honba -= 1
                 }
             }
+            Message::IncrementRiichiSticks => self.riichi_sticks += 1,
+            Message::DecrementRiichiSticks => {
+                if self.riichi_sticks > 0 {
+                    self.riichi_sticks -= 1
+                }
+            }
             Message::StartAddDora => self.phase = Phase::SelectingDora,
             Message::SelectDora(tile) => {
-                self.dora_indicators.push(tile);
+                if self.remaining_tile_count(&tile) > 0 {
+                    self.dora_indicators.push(tile);
+                }
                 self.phase = Phase::Definition;
             }
             Message::StartAddUraDora => self.phase = Phase::SelectingUraDora,
             Message::SelectUraDora(tile) => {
-                self.uradora_indicators.push(tile);
+                if self.remaining_tile_count(&tile) > 0 {
+                    self.uradora_indicators.push(tile);
+                }
                 self.phase = Phase::Definition;
             }
             Message::RemoveDora(index) => {
@@ -199,67 +271,10 @@ impl Update for RiichiGui {
 
             // --- Result Phase ---
             Message::CalculateScore => {
-                if let Some(winning_tile) = self.winning_tile {
-                    let mut hand_tiles = self.hand_tiles.clone();
-
-                    // Filter out Open Melds
-                    for meld in &self.open_melds {
-                        for tile in self.get_meld_tiles(meld) {
-                            if let Some(pos) = hand_tiles.iter().position(|x| *x == tile) {
-                                hand_tiles.remove(pos);
-                            }
-                        }
-                    }
-
-                    // Filter out Closed Kans
-                    for tile in &self.closed_kans {
-                        for _ in 0..4 {
-                            if let Some(pos) = hand_tiles.iter().position(|x| *x == *tile) {
-                                hand_tiles.remove(pos);
-                            }
-                        }
-                    }
-
-                    // Remove winning tile if Ron
-                    if self.agari_type == AgariType::Ron {
-                        if let Some(pos) = hand_tiles.iter().position(|x| *x == winning_tile) {
-                            hand_tiles.remove(pos);
-                        }
-                    }
-
-                    let input = UserInput {
-                        hand_tiles,
-                        open_melds: self.open_melds.clone(),
-                        closed_kans: self.closed_kans.clone(),
-                        winning_tile,
-                        agari_type: self.agari_type,
-                        player_context: PlayerContext {
-                            jikaze: self.jikaze,
-                            is_oya: self.jikaze == Kaze::Ton,
-                            is_riichi: self.is_riichi,
-                            is_daburu_riichi: self.is_daburu_riichi,
-                            is_ippatsu: self.is_ippatsu,
-                            is_menzen: self.open_melds.is_empty(),
-                        },
-                        game_context: GameContext {
-                            bakaze: self.bakaze,
-                            honba: self.honba,
-                            dora_indicators: self.dora_indicators.clone(),
-                            uradora_indicators: self.uradora_indicators.clone(),
-                            num_akadora: self.num_akadora,
-                            is_tenhou: self.is_tenhou,
-                            is_chiihou: self.is_chiihou,
-                            is_renhou: self.is_renhou,
-                            is_haitei: self.is_haitei,
-                            is_houtei: self.is_houtei,
-                            is_rinshan: self.is_rinshan,
-                            is_chankan: self.is_chankan,
-                        },
-                    };
-
+                if let Some(input) = self.build_user_input() {
                     self.score_result = match calculate_agari(&input) {
                         Ok(result) => Some(Ok(result)),
-                        Err(e) => Some(Err(format!("Error: {}", e))),
+                        Err(e) => Some(Err(e.to_string())),
                     };
                     self.phase = Phase::Result;
                 }
@@ -284,5 +299,12 @@ impl Update for RiichiGui {
                 }
             }
         }
+
+        // surface input errors while the hand is being defined
+        if self.phase == Phase::Definition {
+            self.input_error = self
+                .build_user_input()
+                .and_then(|input| validate(&input).err());
+        }
     }
 }
@@ -3,7 +3,8 @@ mod selector_views;
 mod utility_views;
 
 use super::messages::Message;
-use crate::implements::hand::MentsuType;
+use crate::implements::hand::{HandStructure, MentsuType};
+use crate::implements::tiles::Hai;
 use iced::Element;
 
 pub trait View {
@@ -36,6 +37,13 @@ pub trait View {
     #[allow(dead_code)]
     fn view_hand_preview_locked(&self) -> Element<'_, Message>;
 
+    #[allow(dead_code)]
+    fn view_hand_structure(
+        &self,
+        structure: &HandStructure,
+        highlight: &[Hai],
+    ) -> Element<'_, Message>;
+
     #[allow(dead_code)]
     fn view_tile_pool(&self) -> Element<'_, Message>;
 }
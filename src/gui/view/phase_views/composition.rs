@@ -1,7 +1,8 @@
+use crate::gui::components::tile_image;
 use crate::gui::messages::Message;
 use crate::gui::state::RiichiGui;
 use crate::gui::styles::ColoredButtonStyle;
-use iced::widget::{button, column, text};
+use iced::widget::{button, column, row, text};
 use iced::{Color, Element, Length, theme};
 
 pub fn build_composition_view(gui: &RiichiGui) -> Element<'_, Message> {
@@ -22,6 +23,32 @@ pub fn build_composition_view(gui: &RiichiGui) -> Element<'_, Message> {
             ..iced::Font::with_name("Arimo")
         });
 
+    let shanten_text = text(match gui.shanten {
+        Some(-1) => "Complete hand".to_string(),
+        Some(0) => "Tenpai!".to_string(),
+        Some(n) => format!("{}-shanten", n),
+        None => String::new(),
+    })
+    .size(16);
+    let advancing_row = if gui.advancing_tiles.is_empty() {
+        row![]
+    } else {
+        gui.advancing_tiles.iter().fold(
+            row![text("Advancing tiles:").size(14)]
+                .spacing(4)
+                .align_items(iced::Alignment::Center),
+            |tiles, tile| tiles.push(tile_image(&gui.tile_images, tile, 24)),
+        )
+    };
+
+    let key_hint = text(if gui.key_buffer.is_empty() {
+        "Keyboard: 123m 5p e s w n h g r".to_string()
+    } else {
+        format!("Keyboard: {}_", gui.key_buffer)
+    })
+    .size(14)
+    .style(Color::from_rgb(0.5, 0.5, 0.5));
+
     let confirm_btn = button(text("Confirm Hand"))
         .style(theme::Button::Custom(Box::new(ColoredButtonStyle::PRIMARY)))
         .on_press_maybe(if tile_count >= 14 {
@@ -30,8 +57,20 @@ pub fn build_composition_view(gui: &RiichiGui) -> Element<'_, Message> {
             None
         });
 
+    #[cfg(feature = "rand")]
+    let confirm_btn = iced::widget::row![
+        confirm_btn,
+        button(text("Random Hand"))
+            .style(theme::Button::Custom(Box::new(ColoredButtonStyle::INFO)))
+            .on_press(Message::RandomHand)
+    ]
+    .spacing(10);
+
     column![
         counter_text,
+        shanten_text,
+        advancing_row,
+        key_hint,
         hand_preview,
         confirm_btn,
         tile_pool,
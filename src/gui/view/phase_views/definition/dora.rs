@@ -16,12 +16,13 @@ pub fn build_dora_section(gui: &RiichiGui) -> Element<'_, Message> {
                 .iter()
                 .enumerate()
                 .map(|(i, t)| {
-                    let handle = gui
-                        .tile_images
-                        .get(t)
-                        .expect("Tile image not found")
-                        .clone();
-                    tile_image_button(handle, 30, Message::RemoveDora(i), theme::Button::Text)
+                    tile_image_button(
+                        &gui.tile_images,
+                        t,
+                        30,
+                        Message::RemoveDora(i),
+                        theme::Button::Text,
+                    )
                 })
                 .collect::<Vec<Element<Message>>>())
             .spacing(5),
@@ -34,13 +35,9 @@ pub fn build_dora_section(gui: &RiichiGui) -> Element<'_, Message> {
                         .iter()
                         .enumerate()
                         .map(|(i, t)| {
-                            let handle = gui
-                                .tile_images
-                                .get(t)
-                                .expect("Tile image not found")
-                                .clone();
                             tile_image_button(
-                                handle,
+                                &gui.tile_images,
+                                t,
                                 30,
                                 Message::RemoveUraDora(i),
                                 theme::Button::Text,
@@ -36,31 +36,68 @@ pub fn build_game_info_section(gui: &RiichiGui) -> Element<'_, Message> {
         ]
         .spacing(10),
         row![
-            text("Seat Wind:"),
-            radio("East", Kaze::Ton, Some(gui.jikaze), Message::SetJikaze),
-            radio("South", Kaze::Nan, Some(gui.jikaze), Message::SetJikaze),
-            radio("West", Kaze::Shaa, Some(gui.jikaze), Message::SetJikaze),
-            radio("North", Kaze::Pei, Some(gui.jikaze), Message::SetJikaze),
+            text("Kyoku:"),
+            radio("1", 1, Some(gui.kyoku), Message::SetKyoku),
+            radio("2", 2, Some(gui.kyoku), Message::SetKyoku),
+            radio("3", 3, Some(gui.kyoku), Message::SetKyoku),
+            radio("4", 4, Some(gui.kyoku), Message::SetKyoku),
         ]
         .spacing(10),
+        // player 1 deals East 1, the seat wind follows from the kyoku
         row![
-            text(format!("Honba: {}", gui.honba)),
-            action_button("+", Message::IncrementHonba, ColoredButtonStyle::INFO,),
-            button(text("-"))
-                .style(theme::Button::Custom(Box::new(ColoredButtonStyle {
-                    background_color: Color::from_rgb(0.6, 0.0, 0.0),
-                    text_color: Color::WHITE,
-                })))
-                .on_press_maybe(if gui.honba > 0 {
-                    Some(Message::DecrementHonba)
-                } else {
-                    None
-                }),
+            text("Player:"),
+            radio("1", 0, Some(gui.seat), Message::SetSeat),
+            radio("2", 1, Some(gui.seat), Message::SetSeat),
+            radio("3", 2, Some(gui.seat), Message::SetSeat),
+            radio("4", 3, Some(gui.seat), Message::SetSeat),
+            text(format!("Seat Wind: {}", wind_name(gui.jikaze))),
         ]
-        .spacing(10)
-        .align_items(iced::Alignment::Center),
+        .spacing(10),
+        counter_row(
+            "Honba",
+            gui.honba,
+            Message::IncrementHonba,
+            Message::DecrementHonba
+        ),
+        counter_row(
+            "Riichi Sticks",
+            gui.riichi_sticks,
+            Message::IncrementRiichiSticks,
+            Message::DecrementRiichiSticks
+        ),
     ]
     .spacing(15)
     .align_items(iced::Alignment::Center)
     .into()
 }
+
+fn wind_name(kaze: Kaze) -> &'static str {
+    match kaze {
+        Kaze::Ton => "East",
+        Kaze::Nan => "South",
+        Kaze::Shaa => "West",
+        Kaze::Pei => "North",
+    }
+}
+
+// "<label>: n [+] [-]", minus disabled at zero
+fn counter_row<'a>(
+    label: &str,
+    value: u8,
+    increment: Message,
+    decrement: Message,
+) -> Element<'a, Message> {
+    row![
+        text(format!("{}: {}", label, value)),
+        action_button("+", increment, ColoredButtonStyle::INFO),
+        button(text("-"))
+            .style(theme::Button::Custom(Box::new(ColoredButtonStyle {
+                background_color: Color::from_rgb(0.6, 0.0, 0.0),
+                text_color: Color::WHITE,
+            })))
+            .on_press_maybe((value > 0).then_some(decrement)),
+    ]
+    .spacing(10)
+    .align_items(iced::Alignment::Center)
+    .into()
+}
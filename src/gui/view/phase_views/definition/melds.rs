@@ -3,7 +3,7 @@ use crate::gui::components::{action_button, tile_image};
 use crate::gui::messages::Message;
 use crate::gui::state::RiichiGui;
 use crate::gui::styles::ColoredButtonStyle;
-use crate::implements::hand::MentsuType;
+use crate::implements::hand::{KanType, MentsuType};
 use iced::widget::{button, column, row};
 use iced::{Element, theme};
 
@@ -19,14 +19,7 @@ pub fn build_melds_section(gui: &RiichiGui) -> Element<'_, Message> {
                     let tiles = gui.get_meld_tiles(m);
                     let tile_images = row(tiles
                         .iter()
-                        .map(|t| {
-                            let handle = gui
-                                .tile_images
-                                .get(t)
-                                .expect("Tile image not found")
-                                .clone();
-                            tile_image(handle, 40)
-                        })
+                        .map(|t| tile_image(&gui.tile_images, t, 40))
                         .collect::<Vec<Element<Message>>>())
                     .spacing(2);
 
@@ -49,17 +42,10 @@ pub fn build_melds_section(gui: &RiichiGui) -> Element<'_, Message> {
                 .iter()
                 .enumerate()
                 .map(|(i, k)| {
-                    let tiles = vec![*k; 4];
+                    let tiles = [*k; 4];
                     let tile_images = row(tiles
                         .iter()
-                        .map(|t| {
-                            let handle = gui
-                                .tile_images
-                                .get(t)
-                                .expect("Tile image not found")
-                                .clone();
-                            tile_image(handle, 40)
-                        })
+                        .map(|t| tile_image(&gui.tile_images, t, 40))
                         .collect::<Vec<Element<Message>>>())
                     .spacing(2);
                     row![
@@ -86,7 +72,17 @@ pub fn build_melds_section(gui: &RiichiGui) -> Element<'_, Message> {
                 ColoredButtonStyle::INFO,
             ),
             action_button(
-                "Add Kan",
+                "Add Daiminkan",
+                Message::StartAddOpenKan(KanType::Daiminkan),
+                ColoredButtonStyle::INFO,
+            ),
+            action_button(
+                "Add Shouminkan",
+                Message::StartAddOpenKan(KanType::Shouminkan),
+                ColoredButtonStyle::INFO,
+            ),
+            action_button(
+                "Add Ankan",
                 Message::StartAddClosedKan,
                 ColoredButtonStyle::INFO,
             )
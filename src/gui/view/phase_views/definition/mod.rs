@@ -22,7 +22,7 @@ pub fn build_definition_view(gui: &RiichiGui) -> Element<'_, Message> {
 
     let calculate_btn = button(text("Calculate Score"))
         .style(theme::Button::Custom(Box::new(ColoredButtonStyle::PRIMARY)))
-        .on_press_maybe(if gui.winning_tile.is_some() {
+        .on_press_maybe(if gui.winning_tile.is_some() && gui.input_error.is_none() {
             Some(Message::CalculateScore)
         } else {
             None
@@ -50,6 +50,8 @@ pub fn build_definition_view(gui: &RiichiGui) -> Element<'_, Message> {
                 .size(12)
                 .style(Color::from_rgb(0.8, 0.0, 0.0)),
         );
+    } else if let Some(error) = gui.input_error {
+        content = content.push(text(error).size(12).style(Color::from_rgb(0.8, 0.0, 0.0)));
     }
 
     content
@@ -11,13 +11,9 @@ pub fn build_winning_tile_section(gui: &RiichiGui) -> Element<'_, Message> {
         section_header("Winning Tile"),
         match &gui.winning_tile {
             Some(t) => {
-                let handle = gui
-                    .tile_images
-                    .get(t)
-                    .expect("Tile image not found")
-                    .clone();
                 let e: Element<Message> = row![tile_image_button(
-                    handle,
+                    &gui.tile_images,
+                    t,
                     40,
                     Message::StartSelectWinningTile,
                     theme::Button::Text,
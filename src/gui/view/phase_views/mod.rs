@@ -8,7 +8,8 @@ use super::super::messages::Message;
 use super::super::state::{Phase, RiichiGui};
 use super::View;
 use crate::gui::styles::ColoredButtonStyle;
-use crate::implements::hand::MentsuType;
+use crate::implements::hand::{HandStructure, MentsuType};
+use crate::implements::tiles::Hai;
 use iced::widget::{container, scrollable};
 use iced::{Element, Length};
 
@@ -19,6 +20,7 @@ impl View for RiichiGui {
             Phase::Definition => definition::build_definition_view(self),
             Phase::SelectingWinningTile => self.view_selecting_winning_tile(),
             Phase::SelectingMeldTile(m_type) => self.view_selecting_meld_tile(*m_type),
+            Phase::SelectingOpenKan(kan_type) => self.view_selecting_open_kan(*kan_type),
             Phase::SelectingClosedKan => self.view_selecting_closed_kan(),
             Phase::SelectingDora => self.view_selecting_dora(false),
             Phase::SelectingUraDora => self.view_selecting_dora(true),
@@ -97,6 +99,14 @@ impl View for RiichiGui {
         self.view_hand_preview_locked()
     }
 
+    fn view_hand_structure(
+        &self,
+        structure: &HandStructure,
+        highlight: &[Hai],
+    ) -> Element<'_, Message> {
+        self.view_hand_structure(structure, highlight)
+    }
+
     fn view_tile_pool(&self) -> Element<'_, Message> {
         self.view_tile_pool()
     }
@@ -1,10 +1,14 @@
+use crate::gui::components::tile_image;
 use crate::gui::messages::Message;
 use crate::gui::state::RiichiGui;
 use crate::gui::styles::ColoredButtonStyle;
 use crate::implements::game::AgariType;
 use crate::implements::scoring::{AgariResult, HandLimit};
+use crate::implements::tiles::Hai;
 use crate::implements::yaku::Yaku;
-use iced::widget::{button, column, container, text};
+use crate::implements::yaku_checkers::utils::get_dora_tile;
+use crate::implements::{NO_YAKU, NOT_A_WINNING_HAND};
+use iced::widget::{button, column, container, row, text};
 use iced::{Color, Element, Length, theme};
 
 pub fn build_result_view(gui: &RiichiGui) -> Element<'_, Message> {
@@ -21,7 +25,10 @@ pub fn build_result_view(gui: &RiichiGui) -> Element<'_, Message> {
                 honba,
                 agari_type,
                 is_oya,
-                num_akadora,
+                hand_structure,
+                pao_payment,
+                dora_breakdown,
+                ..
             } = result;
 
             let valid_yaku_count = yaku_list
@@ -48,7 +55,26 @@ pub fn build_result_view(gui: &RiichiGui) -> Element<'_, Message> {
                 .align_items(iced::Alignment::Center)
             } else {
                 // Success message
-                let hand_preview = gui.view_hand_preview_locked();
+                // ura dora only count under riichi, one per dora indicator
+                let ura_indicators: &[Hai] = if gui.is_riichi || gui.is_daburu_riichi {
+                    let n = gui.uradora_indicators.len().min(gui.dora_indicators.len());
+                    &gui.uradora_indicators[..n]
+                } else {
+                    &[]
+                };
+                let dora_tiles: Vec<Hai> = gui
+                    .dora_indicators
+                    .iter()
+                    .chain(ura_indicators)
+                    .map(get_dora_tile)
+                    .collect();
+                let hand_preview = gui.view_hand_structure(hand_structure, &dora_tiles);
+                let indicator_section = build_indicator_section(
+                    gui,
+                    &gui.dora_indicators,
+                    ura_indicators,
+                    dora_breakdown.aka,
+                );
                 // Total Score
                 let score_text = text(format!("{} Points", total_payment))
                     .size(40)
@@ -59,17 +85,7 @@ pub fn build_result_view(gui: &RiichiGui) -> Element<'_, Message> {
                     });
 
                 // Limit Name
-                let limit_str = if let Some(limit) = limit_name {
-                    Some(match limit {
-                        HandLimit::Mangan => "Mangan",
-                        HandLimit::Haneman => "Haneman",
-                        HandLimit::Baiman => "Baiman",
-                        HandLimit::Sanbaiman => "Sanbaiman",
-                        HandLimit::Yakuman => "Yakuman",
-                    })
-                } else {
-                    None
-                };
+                let limit_str = result.limit_label();
 
                 // Han/Fu Display
                 let han_fu_text = if limit_name.as_ref() == Some(&HandLimit::Yakuman) {
@@ -80,14 +96,9 @@ pub fn build_result_view(gui: &RiichiGui) -> Element<'_, Message> {
 
                 // Yaku List Display
                 let mut yaku_col = column![];
-                let mut dora_count = 0;
-                let mut uradora_count = 0;
-
                 for yaku in yaku_list {
                     match yaku {
-                        Yaku::Dora => dora_count += 1,
-                        Yaku::UraDora => uradora_count += 1,
-                        Yaku::AkaDora => {}
+                        Yaku::Dora | Yaku::UraDora | Yaku::AkaDora => {}
                         _ => {
                             yaku_col = yaku_col.push(text(format!("• {:?}", yaku)).size(18).font(
                                 iced::Font {
@@ -100,15 +111,15 @@ pub fn build_result_view(gui: &RiichiGui) -> Element<'_, Message> {
                 }
 
                 // Append bonus han counts
-                if dora_count > 0 {
-                    yaku_col = yaku_col.push(text(format!("• Dora x{}", dora_count)).size(18));
-                }
-                if uradora_count > 0 {
-                    yaku_col =
-                        yaku_col.push(text(format!("• Ura Dora x{}", uradora_count)).size(18));
-                }
-                if *num_akadora > 0 {
-                    yaku_col = yaku_col.push(text(format!("• Aka Dora x{}", num_akadora)).size(18));
+                for (label, count) in [
+                    ("Dora", dora_breakdown.dora),
+                    ("Kan Dora", dora_breakdown.kan_dora),
+                    ("Ura Dora", dora_breakdown.ura),
+                    ("Aka Dora", dora_breakdown.aka),
+                ] {
+                    if count > 0 {
+                        yaku_col = yaku_col.push(text(format!("• {} x{}", label, count)).size(18));
+                    }
                 }
 
                 // Payment Detail Breakdown
@@ -144,6 +155,33 @@ pub fn build_result_view(gui: &RiichiGui) -> Element<'_, Message> {
                     }
                 };
 
+                let payment_text = if *pao_payment > 0 {
+                    format!(
+                        "{}\nLiable player (pao) pays: {}",
+                        payment_text, pao_payment
+                    )
+                } else {
+                    payment_text
+                };
+
+                // table bonuses on their own lines, the GUI always plays yonma
+                let mut payment_text = payment_text;
+                if *honba > 0 {
+                    payment_text = format!(
+                        "{}\nHonba bonus: {} x 300 = {}",
+                        payment_text, honba, ron_bonus
+                    );
+                }
+                if result.riichi_sticks > 0 {
+                    payment_text = format!(
+                        "{}\nRiichi sticks: {} x 1000 = {}\nWinner collects: {}",
+                        payment_text,
+                        result.riichi_sticks,
+                        result.riichi_bonus(),
+                        total_payment + result.riichi_bonus()
+                    );
+                }
+
                 let payment_section = container(text(payment_text).size(16).font(iced::Font {
                     weight: iced::font::Weight::Bold,
                     ..iced::Font::with_name("Arimo")
@@ -152,6 +190,7 @@ pub fn build_result_view(gui: &RiichiGui) -> Element<'_, Message> {
 
                 let mut result_column = column![
                     hand_preview,
+                    indicator_section,
                     iced::widget::Space::with_height(Length::Fixed(20.0)),
                     score_text
                 ];
@@ -178,14 +217,19 @@ pub fn build_result_view(gui: &RiichiGui) -> Element<'_, Message> {
                 result_column
             }
         }
-        Some(Err(_)) => column![
-            text("No Yaku Found")
-                .size(30)
-                .style(Color::from_rgb(0.8, 0.0, 0.0))
-                .font(iced::Font {
-                    weight: iced::font::Weight::Bold,
-                    ..iced::Font::with_name("Arimo")
-                })
+        Some(Err(e)) => column![
+            text(match e.as_str() {
+                NO_YAKU => "No Yaku Found",
+                NOT_A_WINNING_HAND => "Not a Winning Hand",
+                _ => "Invalid Hand",
+            })
+            .size(30)
+            .style(Color::from_rgb(0.8, 0.0, 0.0))
+            .font(iced::Font {
+                weight: iced::font::Weight::Bold,
+                ..iced::Font::with_name("Arimo")
+            }),
+            text(e).size(16).style(Color::from_rgb(0.5, 0.5, 0.5))
         ]
         .spacing(15)
         .align_items(iced::Alignment::Center),
@@ -203,3 +247,33 @@ pub fn build_result_view(gui: &RiichiGui) -> Element<'_, Message> {
     .align_items(iced::Alignment::Center)
     .into()
 }
+
+// "Dora: [indicator] -> [dora]" rows for dora and counted ura dora, plus the aka count
+fn build_indicator_section<'a>(
+    gui: &'a RiichiGui,
+    dora_indicators: &[Hai],
+    ura_indicators: &[Hai],
+    num_akadora: u8,
+) -> Element<'a, Message> {
+    let mut section = column![].spacing(8).align_items(iced::Alignment::Center);
+    for (label, indicators) in [("Dora", dora_indicators), ("Ura Dora", ura_indicators)] {
+        if indicators.is_empty() {
+            continue;
+        }
+        let mut indicator_row = row![text(format!("{}:", label)).size(16)]
+            .spacing(6)
+            .align_items(iced::Alignment::Center);
+        for indicator in indicators {
+            indicator_row = indicator_row
+                .push(tile_image(&gui.tile_images, indicator, 30))
+                .push(text("->").size(14))
+                .push(tile_image(&gui.tile_images, &get_dora_tile(indicator), 30))
+                .push(iced::widget::Space::with_width(Length::Fixed(10.0)));
+        }
+        section = section.push(indicator_row);
+    }
+    if num_akadora > 0 {
+        section = section.push(text(format!("Aka Dora: {}", num_akadora)).size(16));
+    }
+    section.into()
+}
@@ -1,28 +1,24 @@
-use super::super::components::{cancel_button, create_grid, tile_button};
+use super::super::components::{cancel_button, create_grid, tile_button, tile_image};
 use super::super::messages::Message;
 use super::super::state::RiichiGui;
 use super::super::styles::ColoredButtonStyle;
-use crate::implements::hand::MentsuType;
-use crate::implements::tiles::Hai;
-use iced::widget::{column, container, row, text};
-use iced::{Element, theme};
+use crate::implements::hand::{KanType, MentsuType};
+use crate::implements::input::OpenMeldInput;
+use crate::implements::tiles::{Hai, sort_tiles};
+use iced::widget::{button, column, container, row, text};
+use iced::{Color, Element, theme};
 
 impl RiichiGui {
     pub fn view_selecting_winning_tile(&self) -> Element<'_, Message> {
-        let mut unique_tiles: Vec<Hai> = self.hand_tiles.iter().map(|t| *t).collect();
-        unique_tiles.sort();
+        let mut unique_tiles: Vec<Hai> = self.hand_tiles.to_vec();
+        sort_tiles(&mut unique_tiles);
         unique_tiles.dedup();
 
         let tiles: Vec<Element<Message>> = unique_tiles
             .iter()
             .map(|tile| {
-                let handle = self
-                    .tile_images
-                    .get(tile)
-                    .expect("Tile image not found")
-                    .clone();
                 tile_button(
-                    iced::widget::Image::new(handle).width(50).into(),
+                    tile_image(&self.tile_images, tile, 50),
                     Message::SelectWinningTile(*tile),
                     ColoredButtonStyle::NEUTRAL,
                 )
@@ -43,14 +39,25 @@ impl RiichiGui {
         let possible_melds = match m_type {
             MentsuType::Koutsu => self.get_all_possible_pons(),
             MentsuType::Shuntsu => self.get_all_possible_chiis(),
-            MentsuType::Kantsu => {
-                vec![]
-            }
+            MentsuType::Kantsu => self.get_all_possible_open_kans(KanType::Daiminkan),
         };
 
+        self.view_meld_choices(format!("{:?}", m_type), possible_melds)
+    }
+
+    pub fn view_selecting_open_kan(&self, kan_type: KanType) -> Element<'_, Message> {
+        let possible_kans = self.get_all_possible_open_kans(kan_type);
+        self.view_meld_choices(format!("{:?}", kan_type), possible_kans)
+    }
+
+    fn view_meld_choices(
+        &self,
+        name: String,
+        possible_melds: Vec<OpenMeldInput>,
+    ) -> Element<'_, Message> {
         if possible_melds.is_empty() {
             return column![
-                text(format!("No valid {:?} available", m_type)).size(24),
+                text(format!("No valid {} available", name)).size(24),
                 cancel_button()
             ]
             .spacing(20)
@@ -64,14 +71,7 @@ impl RiichiGui {
                 let tiles = self.get_meld_tiles(meld);
                 let tile_images = row(tiles
                     .iter()
-                    .map(|t| {
-                        let handle = self
-                            .tile_images
-                            .get(t)
-                            .expect("Tile image not found")
-                            .clone();
-                        iced::widget::Image::new(handle).width(50).into()
-                    })
+                    .map(|t| tile_image(&self.tile_images, t, 50))
                     .collect::<Vec<Element<Message>>>())
                 .spacing(2);
 
@@ -83,12 +83,11 @@ impl RiichiGui {
                     Message::SelectCompleteMeld(meld.clone()),
                     ColoredButtonStyle::NEUTRAL_HOVER,
                 )
-                .into()
             })
             .collect();
 
         column![
-            text(format!("Select {:?}", m_type)).size(24),
+            text(format!("Select {}", name)).size(24),
             create_grid(meld_buttons, 5),
             cancel_button()
         ]
@@ -110,17 +109,10 @@ impl RiichiGui {
         let kan_buttons: Vec<Element<Message>> = possible_kans
             .iter()
             .map(|tile| {
-                let tiles = vec![*tile; 4];
+                let tiles = [*tile; 4];
                 let tile_images = row(tiles
                     .iter()
-                    .map(|t| {
-                        let handle = self
-                            .tile_images
-                            .get(t)
-                            .expect("Tile image not found")
-                            .clone();
-                        iced::widget::Image::new(handle).width(50).into()
-                    })
+                    .map(|t| tile_image(&self.tile_images, t, 50))
                     .collect::<Vec<Element<Message>>>())
                 .spacing(2);
 
@@ -132,7 +124,6 @@ impl RiichiGui {
                     Message::SelectClosedKan(*tile),
                     ColoredButtonStyle::NEUTRAL_HOVER,
                 )
-                .into()
             })
             .collect();
 
@@ -149,23 +140,24 @@ impl RiichiGui {
     pub fn view_selecting_dora(&self, is_ura: bool) -> Element<'_, Message> {
         let mut tiles = Vec::new();
 
-        for i in 0..34 {
-            let tile = crate::implements::tiles::index_to_tile(i);
-            let handle = self
-                .tile_images
-                .get(&tile)
-                .expect("Tile image not found")
-                .clone();
-
-            let btn = tile_button(
-                iced::widget::Image::new(handle).width(40).into(),
-                if is_ura {
+        for tile in Hai::all() {
+            let available = self.remaining_tile_count(&tile) > 0;
+            let btn = button(tile_image(&self.tile_images, &tile, 40))
+                .style(theme::Button::Custom(Box::new(if available {
+                    ColoredButtonStyle::NEUTRAL
+                } else {
+                    ColoredButtonStyle {
+                        background_color: Color::from_rgb(0.85, 0.85, 0.85),
+                        text_color: Color::BLACK,
+                    }
+                })))
+                .on_press_maybe(available.then_some(if is_ura {
                     Message::SelectUraDora(tile)
                 } else {
                     Message::SelectDora(tile)
-                },
-                ColoredButtonStyle::NEUTRAL,
-            );
+                }))
+                .padding(5)
+                .into();
 
             tiles.push(btn);
         }
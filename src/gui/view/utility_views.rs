@@ -2,8 +2,10 @@ use super::super::messages::Message;
 
 use super::super::components::{action_button, tile_image, tile_image_button};
 use super::super::state::RiichiGui;
-use super::super::styles::ColoredButtonStyle;
-use iced::widget::{button, column, container, image, row, text};
+use super::super::styles::{ColoredButtonStyle, HighlightStyle};
+use crate::implements::hand::HandStructure;
+use crate::implements::tiles::{Hai, sort_tiles};
+use iced::widget::{button, column, container, row, text};
 use iced::{Color, Element, Length, theme};
 
 impl RiichiGui {
@@ -13,13 +15,9 @@ impl RiichiGui {
             .iter()
             .enumerate()
             .map(|(i, tile)| {
-                let handle = self
-                    .tile_images
-                    .get(tile)
-                    .expect("Tile image not found")
-                    .clone();
                 tile_image_button(
-                    handle,
+                    &self.tile_images,
+                    tile,
                     40,
                     Message::RemoveTile(i),
                     theme::Button::Custom(Box::new(ColoredButtonStyle::NEUTRAL)),
@@ -37,33 +35,67 @@ impl RiichiGui {
         let tiles: Vec<Element<Message>> = self
             .hand_tiles
             .iter()
-            .enumerate()
-            .map(|(_, tile)| {
-                let handle = self
-                    .tile_images
-                    .get(tile)
-                    .expect("Tile image not found")
-                    .clone();
-                tile_image(handle, 40)
-            })
+            .map(|tile| tile_image(&self.tile_images, tile, 40))
             .collect();
 
         row(tiles).spacing(5).into()
     }
 
+    // winning decomposition, one group per meld/pair, tiles in highlight framed
+    pub fn view_hand_structure(
+        &self,
+        structure: &HandStructure,
+        highlight: &[Hai],
+    ) -> Element<'_, Message> {
+        let groups: Vec<Vec<Hai>> = match structure {
+            HandStructure::YonmentsuIchiatama(hand) | HandStructure::ChuurenPoutou { hand, .. } => {
+                let mut groups: Vec<Vec<Hai>> = hand
+                    .mentsu
+                    .iter()
+                    .map(|m| m.tiles_slice().to_vec())
+                    .collect();
+                groups.push(vec![hand.atama.0, hand.atama.1]);
+                groups
+            }
+            HandStructure::Chiitoitsu { pairs, .. } => {
+                pairs.iter().map(|&(t1, t2)| vec![t1, t2]).collect()
+            }
+            HandStructure::KokushiMusou { .. } => {
+                let mut all = structure.tiles();
+                sort_tiles(&mut all);
+                vec![all]
+            }
+        };
+
+        let group_rows: Vec<Element<Message>> = groups
+            .iter()
+            .map(|group| {
+                let tiles: Vec<Element<Message>> = group
+                    .iter()
+                    .map(|tile| {
+                        let frame = container(tile_image(&self.tile_images, tile, 40)).padding(2);
+                        if highlight.contains(tile) {
+                            frame
+                                .style(theme::Container::Custom(Box::new(HighlightStyle)))
+                                .into()
+                        } else {
+                            frame.into()
+                        }
+                    })
+                    .collect();
+                row(tiles).spacing(2).into()
+            })
+            .collect();
+
+        row(group_rows).spacing(15).into()
+    }
+
     pub fn view_tile_pool(&self) -> Element<'_, Message> {
         let mut tiles = Vec::new();
 
-        for i in 0..34 {
-            let tile = crate::implements::tiles::index_to_tile(i);
-            let count = self.tile_counts[i];
-            let handle = self
-                .tile_images
-                .get(&tile)
-                .expect("Tile image not found")
-                .clone();
-
-            let tile_image = image(handle).width(50);
+        for tile in Hai::all() {
+            let count = self.remaining_tile_count(&tile);
+            let tile_element = tile_image(&self.tile_images, &tile, 50);
 
             let count_text = text(format!("({})", count)).size(12).style(if count > 0 {
                 Color::BLACK
@@ -77,18 +109,19 @@ impl RiichiGui {
                 Color::from_rgb(0.85, 0.85, 0.85)
             };
 
-            let btn = button(column![tile_image, count_text].align_items(iced::Alignment::Center))
-                .style(theme::Button::Custom(Box::new(ColoredButtonStyle {
-                    background_color: button_bg_color,
-                    text_color: Color::BLACK,
-                })))
-                .on_press_maybe(if count > 0 {
-                    Some(Message::AddTile(tile))
-                } else {
-                    None
-                })
-                .padding(5)
-                .into();
+            let btn =
+                button(column![tile_element, count_text].align_items(iced::Alignment::Center))
+                    .style(theme::Button::Custom(Box::new(ColoredButtonStyle {
+                        background_color: button_bg_color,
+                        text_color: Color::BLACK,
+                    })))
+                    .on_press_maybe(if count > 0 {
+                        Some(Message::AddTile(tile))
+                    } else {
+                        None
+                    })
+                    .padding(5)
+                    .into();
 
             tiles.push(btn);
         }
@@ -0,0 +1,165 @@
+use crate::implements::types::{
+    game::{AgariType, GameContext, PlayerContext, seat_wind},
+    scoring::AgariResult,
+    tiles::Kaze,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+// a yonma game across hands, players are indexed 0-3 with player 0 dealing East 1
+pub struct GameState {
+    pub scores: [i32; 4],
+    pub bakaze: Kaze,     // 場風 (current round)
+    pub kyoku: u8,        // 局 (from 1)
+    pub honba: u8,        // 本場
+    pub riichi_bou: u8,   // 供託 (sticks on the table)
+    pub last_round: Kaze, // Ton for tonpuusen, Nan for hanchan
+    pub finished: bool,   // the last round's 4th kyoku passed the deal
+}
+
+impl GameState {
+    pub fn new(starting_points: i32, last_round: Kaze) -> Self {
+        GameState {
+            scores: [starting_points; 4],
+            bakaze: Kaze::Ton,
+            kyoku: 1,
+            honba: 0,
+            riichi_bou: 0,
+            last_round,
+            finished: false,
+        }
+    }
+
+    pub fn dealer(&self) -> u8 {
+        (self.kyoku + 3) % 4
+    }
+
+    pub fn seat_wind(&self, player: u8) -> Kaze {
+        seat_wind(self.kyoku, player)
+    }
+
+    // round, hand and table counters for the next agari, the caller adds dora indicators and flags
+    pub fn game_context(&self) -> GameContext {
+        GameContext {
+            bakaze: self.bakaze,
+            kyoku: self.kyoku,
+            honba: self.honba,
+            riichi_sticks: self.riichi_bou,
+            ..Default::default()
+        }
+    }
+
+    // closed hand without riichi in the player's seat
+    pub fn player_context(&self, player: u8) -> PlayerContext {
+        PlayerContext {
+            jikaze: self.seat_wind(player),
+            is_oya: player == self.dealer(),
+            ..Default::default()
+        }
+    }
+
+    // 立直: the player puts a 1000 stick on the table
+    pub fn declare_riichi(&mut self, player: u8) {
+        self.scores[player as usize] -= 1000;
+        self.riichi_bou += 1;
+    }
+
+    // discarder is the ron victim (ignored on tsumo), pao the liable player if any;
+    // the winner also takes every riichi stick on the table
+    pub fn apply_agari(
+        &mut self,
+        winner: u8,
+        result: &AgariResult,
+        discarder: Option<u8>,
+        pao: Option<u8>,
+    ) -> Result<(), &'static str> {
+        if winner > 3 || discarder.is_some_and(|p| p > 3) || pao.is_some_and(|p| p > 3) {
+            return Err("Player index must be between 0 and 3");
+        }
+        if result.is_oya != (winner == self.dealer()) {
+            return Err("The result's dealer flag does not match the winner's seat");
+        }
+        if result.pao_payment > 0 && pao.is_none() {
+            return Err("A pao payment needs the liable player");
+        }
+        let pao = pao.filter(|_| result.pao_payment > 0);
+
+        let mut paid = [0u32; 4];
+        match result.agari_type {
+            AgariType::Ron => {
+                let Some(discarder) = discarder.filter(|&p| p != winner) else {
+                    return Err("Ron needs a discarder other than the winner");
+                };
+                paid[discarder as usize] += result.total_payment - result.pao_payment;
+                if let Some(pao) = pao {
+                    paid[pao as usize] += result.pao_payment;
+                }
+            }
+            // pao covers the whole tsumo
+            AgariType::Tsumo if result.pao_payment == result.total_payment => {
+                paid[pao.unwrap_or(winner) as usize] += result.total_payment;
+            }
+            AgariType::Tsumo => {
+                let honba_each = result.honba as u32 * 100;
+                for payer in (0..4).filter(|&p| p != winner) {
+                    let share = if result.is_oya || payer == self.dealer() {
+                        result.oya_payment
+                    } else {
+                        result.ko_payment
+                    };
+                    paid[payer as usize] += share + honba_each;
+                }
+                if let Some(pao) = pao {
+                    paid[pao as usize] += result.pao_payment;
+                }
+            }
+        }
+
+        for (score, amount) in self.scores.iter_mut().zip(paid) {
+            *score -= amount as i32;
+        }
+        self.scores[winner as usize] +=
+            paid.iter().sum::<u32>() as i32 + self.riichi_bou as i32 * 1000;
+        self.riichi_bou = 0;
+
+        // 連荘: the dealer keeps the deal and adds a honba
+        if winner == self.dealer() {
+            self.honba += 1;
+        } else {
+            self.honba = 0;
+            self.pass_deal();
+        }
+        Ok(())
+    }
+
+    // 流局: noten players pay 3000 split among the tenpai ones, sticks stay on the table
+    pub fn apply_ryuukyoku(&mut self, tenpai: [bool; 4]) {
+        let n = tenpai.iter().filter(|&&t| t).count() as i32;
+        if n > 0 && n < 4 {
+            for (score, is_tenpai) in self.scores.iter_mut().zip(tenpai) {
+                *score += if is_tenpai { 3000 / n } else { -3000 / (4 - n) };
+            }
+        }
+
+        self.honba += 1;
+        if !tenpai[self.dealer() as usize] {
+            self.pass_deal();
+        }
+    }
+
+    // 飛び (a player below zero) or past the last round
+    pub fn is_over(&self) -> bool {
+        self.finished || self.scores.iter().any(|&s| s < 0)
+    }
+
+    fn pass_deal(&mut self) {
+        if self.kyoku < 4 {
+            self.kyoku += 1;
+        } else if self.bakaze == self.last_round {
+            self.finished = true;
+        } else {
+            self.kyoku = 1;
+            self.bakaze = self.bakaze.next_dora();
+        }
+    }
+}
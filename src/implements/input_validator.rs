@@ -0,0 +1,270 @@
+use crate::implements::types::{
+    game::{AgariType, GameContext},
+    hand::{HandMeta, MentsuType},
+    input::{OpenMeldInput, UserInput},
+    rules::PlayMode,
+    tiles::{Hai, Kaze, Suhai, index_to_tile, tile_to_index},
+};
+
+pub fn validate_input(input: &UserInput) -> Result<(), &'static str> {
+    validate_game_state(input)?;
+    validate_hand_size(input)?;
+    validate_akadora_count(input)?;
+    validate_closed_kans(input)?;
+    validate_tile_counts(input)?;
+    validate_called_tiles(input)?;
+    Ok(())
+}
+
+// flag combinations that cannot happen in one win
+fn validate_game_state(input: &UserInput) -> Result<(), &'static str> {
+    let player = &input.player_context;
+    let game = &input.game_context;
+
+    validate_game_context(game)?;
+    if player.is_ippatsu && !(player.is_riichi || player.is_daburu_riichi) {
+        return Err("Ippatsu requires Riichi");
+    }
+    // the winner's own kan breaks ippatsu, calls by others must be cleared by the caller
+    if player.is_ippatsu && game.is_rinshan {
+        return Err("Ippatsu cannot be combined with Rinshan");
+    }
+    // 天和/地和 are won on the first draw, before any call or kan
+    if game.is_tenhou || game.is_chiihou {
+        if game.is_tenhou && !player.is_oya {
+            return Err("Tenhou requires the dealer");
+        }
+        if game.is_chiihou && player.is_oya {
+            return Err("Chiihou requires a non-dealer");
+        }
+        if input.agari_type != AgariType::Tsumo {
+            return Err("Tenhou and Chiihou require a Tsumo");
+        }
+        if !player.is_menzen || !input.open_melds.is_empty() {
+            return Err("Tenhou and Chiihou require a closed hand with no calls");
+        }
+        if game.is_rinshan {
+            return Err("Tenhou and Chiihou cannot be won on a kan replacement draw");
+        }
+    }
+    // the replacement draw only follows the winner's own kan
+    let has_kan = !input.closed_kans.is_empty()
+        || input
+            .open_melds
+            .iter()
+            .any(|m| m.mentsu_type == MentsuType::Kantsu);
+    if game.is_rinshan && (input.agari_type != AgariType::Tsumo || !has_kan) {
+        return Err("Rinshan requires a Tsumo after declaring a kan");
+    }
+    if game.is_chankan && input.agari_type != AgariType::Ron {
+        return Err("Chankan requires a Ron on the added kan tile");
+    }
+    // the robbed pon holds the other 3 copies of the winning tile
+    if game.is_chankan && holds_tile(input, &input.winning_tile) {
+        return Err("Chankan tile cannot also be in the winner's hand or melds");
+    }
+    if game.rules.play_mode == PlayMode::Sanma && player.jikaze == Kaze::Pei {
+        return Err("There is no North seat or round in sanma");
+    }
+    if game.pao_player == Some(player.jikaze) {
+        return Err("Pao player cannot be the winner");
+    }
+    Ok(())
+}
+
+// the GameContext flags on their own, also run by GameContextBuilder::build
+pub fn validate_game_context(game: &GameContext) -> Result<(), &'static str> {
+    let first_turn_wins = [game.is_tenhou, game.is_chiihou, game.is_renhou];
+    if first_turn_wins.iter().filter(|&&f| f).count() > 1 {
+        return Err("Only one of Tenhou, Chiihou and Renhou can apply");
+    }
+    if game.is_rinshan && game.is_haitei && !game.rules.rinshan_haitei_allowed {
+        return Err("Rinshan cannot be combined with Haitei");
+    }
+    // a robbed kan tile is never the last discard
+    if game.is_chankan && game.is_houtei {
+        return Err("Chankan cannot be combined with Houtei");
+    }
+    if game.rules.play_mode == PlayMode::Sanma && game.bakaze == Kaze::Pei {
+        return Err("There is no North seat or round in sanma");
+    }
+    let kyoku_count = if game.rules.play_mode == PlayMode::Sanma {
+        3
+    } else {
+        4
+    };
+    if !(1..=kyoku_count).contains(&game.kyoku) {
+        return Err("Kyoku must be between 1 and 4 (3 in sanma)");
+    }
+    Ok(())
+}
+
+// hand_tiles leaves out every declared meld, so each one, kans included, takes
+// 3 of the 14 tiles: 14 concealed with no melds, 11 with one, ..., 2 with four
+fn validate_hand_size(input: &UserInput) -> Result<(), &'static str> {
+    let num_melds = input.open_melds.len() + input.closed_kans.len();
+    if num_melds > 4 {
+        return Err("Too many melds declared (max 4)");
+    }
+    let mut concealed = input.hand_tiles.len();
+    if input.agari_type == AgariType::Ron {
+        concealed += 1;
+    }
+    if concealed + 3 * num_melds != 14 {
+        return Err("Hand must hold 14 tiles less 3 per declared meld (winning tile included)");
+    }
+    Ok(())
+}
+
+// 1 dora indicator + 1 per kan, ura dora mirrors dora under riichi;
+// no indicators at all means dora are not being scored and is accepted.
+// Runs after organize_hand_with_meta, which counts the kans
+pub fn validate_dora_count(input: &UserInput, meta: &HandMeta) -> Result<(), &'static str> {
+    let player = &input.player_context;
+    let game = &input.game_context;
+
+    let result =
+        if !game.dora_indicators.is_empty() && game.dora_indicators.len() != 1 + meta.kan_count {
+            Err("Dora indicator count must be 1 + number of kans")
+        } else if (player.is_riichi || player.is_daburu_riichi)
+            && !game.uradora_indicators.is_empty()
+            && game.uradora_indicators.len() != game.dora_indicators.len()
+        {
+            Err("Ura dora indicator count must match dora indicator count")
+        } else {
+            Ok(())
+        };
+
+    match result {
+        Err(_e) if game.rules.lenient_dora_count => {
+            warn_log!("{}", _e);
+            Ok(())
+        }
+        _ => result,
+    }
+}
+
+// every aka dora must be one of the fives in hand, kans contribute all 4 tiles
+fn validate_akadora_count(input: &UserInput) -> Result<(), &'static str> {
+    let is_five = |tile: &Hai| matches!(tile, Hai::Suhai(Suhai { number: 5, .. }));
+
+    let mut num_fives = input.hand_tiles.iter().filter(|t| is_five(t)).count();
+    if input.agari_type == AgariType::Ron && is_five(&input.winning_tile) {
+        num_fives += 1;
+    }
+    for meld in &input.open_melds {
+        num_fives += match meld.mentsu_type {
+            MentsuType::Kantsu if is_five(&meld.representative_tile) => 4,
+            MentsuType::Koutsu if is_five(&meld.representative_tile) => 3,
+            // representative tile is the lowest of the run
+            MentsuType::Shuntsu => match meld.representative_tile {
+                Hai::Suhai(Suhai { number: 3..=5, .. }) => 1,
+                _ => 0,
+            },
+            _ => 0,
+        };
+    }
+    num_fives += 4 * input.closed_kans.iter().filter(|t| is_five(t)).count();
+
+    if input.game_context.num_akadora as usize > num_fives.min(4) {
+        return Err("Aka dora count exceeds the number of fives in hand");
+    }
+    Ok(())
+}
+
+// a closed kan uses all 4 copies, so its tile can't be in another kan or an open meld
+fn validate_closed_kans(input: &UserInput) -> Result<(), &'static str> {
+    for (i, tile) in input.closed_kans.iter().enumerate() {
+        if input.closed_kans[..i].contains(tile) {
+            return Err("The same tile is declared as a closed kan twice");
+        }
+        if input
+            .open_melds
+            .iter()
+            .any(|meld| meld_contains(meld, tile))
+        {
+            return Err("A closed kan tile is also part of an open meld");
+        }
+    }
+    Ok(())
+}
+
+// no tile more than 4 times across the concealed tiles, melds and kans
+// malformed tiles and chi are left to organize_hand to report
+fn validate_tile_counts(input: &UserInput) -> Result<(), &'static str> {
+    let mut tiles: Vec<Hai> = input.hand_tiles.clone();
+    if input.agari_type == AgariType::Ron {
+        tiles.push(input.winning_tile);
+    }
+    for meld in &input.open_melds {
+        let rep_tile = meld.representative_tile;
+        if !rep_tile.is_valid() {
+            continue;
+        }
+        match meld.mentsu_type {
+            MentsuType::Koutsu => tiles.extend([rep_tile; 3]),
+            MentsuType::Kantsu => tiles.extend([rep_tile; 4]),
+            MentsuType::Shuntsu => {
+                let index = tile_to_index(&rep_tile);
+                if index < 27 && index % 9 < 7 {
+                    tiles.extend((index..index + 3).map(index_to_tile));
+                }
+            }
+        }
+    }
+    for tile in &input.closed_kans {
+        tiles.extend([*tile; 4]);
+    }
+
+    let mut counts = [0u8; 34];
+    for tile in tiles.iter().filter(|t| t.is_valid()) {
+        counts[tile_to_index(tile)] += 1;
+    }
+    if counts.iter().any(|&n| n > 4) {
+        return Err("More than 4 copies of a tile");
+    }
+    Ok(())
+}
+
+// optional call details must agree with the meld and the seats
+fn validate_called_tiles(input: &UserInput) -> Result<(), &'static str> {
+    let jikaze = input.player_context.jikaze;
+
+    for meld in &input.open_melds {
+        if let Some(tile) = meld.called_tile
+            && !meld_contains(meld, &tile)
+        {
+            return Err("Called tile is not part of its meld");
+        }
+        if let Some(from) = meld.called_from {
+            if from == jikaze {
+                return Err("A meld cannot be called from the player's own discard");
+            }
+            // the left player discards right before us
+            if meld.mentsu_type == MentsuType::Shuntsu && from.next_dora() != jikaze {
+                return Err("Chi can only be called from the player to the left");
+            }
+        }
+    }
+    Ok(())
+}
+
+fn holds_tile(input: &UserInput, tile: &Hai) -> bool {
+    input.hand_tiles.contains(tile)
+        || input.closed_kans.contains(tile)
+        || input
+            .open_melds
+            .iter()
+            .any(|meld| meld_contains(meld, tile))
+}
+
+// a chi holds its representative (lowest) tile and the next two of the suit
+fn meld_contains(meld: &OpenMeldInput, tile: &Hai) -> bool {
+    match (meld.mentsu_type, meld.representative_tile, tile) {
+        (MentsuType::Koutsu | MentsuType::Kantsu, rep_tile, tile) => rep_tile == *tile,
+        (MentsuType::Shuntsu, Hai::Suhai(low), Hai::Suhai(t)) => {
+            low.suit == t.suit && (low.number..low.number + 3).contains(&t.number)
+        }
+        (MentsuType::Shuntsu, _, _) => false,
+    }
+}
@@ -1,38 +1,207 @@
+// debug-level diagnostics, compiled out (arguments included) without the log feature
+macro_rules! debug_log {
+    ($($arg:tt)*) => {{
+        #[cfg(feature = "log")]
+        log::debug!($($arg)*);
+    }};
+}
+
+// warnings a caller opted into instead of an error, dropped without the log feature
+macro_rules! warn_log {
+    ($($arg:tt)*) => {{
+        #[cfg(feature = "log")]
+        log::warn!($($arg)*);
+    }};
+}
+
 pub mod types;
 pub use types::*;
+pub mod input_validator;
+pub mod notation;
 pub mod raw_hand_organizer;
 pub use raw_hand_organizer::*;
 pub mod yaku_checkers;
 pub use yaku_checkers::*;
 pub mod score_calculator;
 pub use score_calculator::*;
+pub mod tenpai;
+pub use tenpai::*;
+pub mod game_state;
+#[cfg(feature = "rand")]
+pub mod random_hand;
+#[cfg(feature = "tenhou")]
+pub mod tenhou;
+#[cfg(feature = "rand")]
+pub use random_hand::*;
 
+use crate::implements::game::{AgariType, GameContext, PlayerContext};
+use crate::implements::hand::Mentsu;
 use crate::implements::input::UserInput;
-use crate::implements::scoring::AgariResult;
+use crate::implements::scoring::{AgariResult, ScoreTrace};
+use crate::implements::tiles::{Hai, index_to_tile};
+use crate::implements::yaku::Yaku;
 
+// calculate_agari's two ways a well-formed input can fail to score
+pub const NO_YAKU: &str = "Complete hand, but no yaku (dora alone cannot win)";
+pub const NOT_A_WINNING_HAND: &str = "Not a complete winning hand";
+
+// dora han the hand holds, so a NO_YAKU result can tell the player what it was worth
+pub fn dora_count(input: &UserInput) -> Result<u8, &'static str> {
+    let (mut tiles, melds) = split_input(input)?;
+    tiles.push(input.winning_tile);
+    tiles.extend(melds.iter().flat_map(Mentsu::tiles_slice));
+    let dora = yaku_checkers::utils::count_dora_breakdown(
+        &tiles,
+        &input.player_context,
+        &input.game_context,
+    );
+    Ok(dora.dora + dora.kan_dora + dora.ura + dora.aka)
+}
+
+// every decomposition and wait is scored, highest-value reading wins
 pub fn calculate_agari(input: &UserInput) -> Result<AgariResult, &'static str> {
     let player = &input.player_context;
     let game = &input.game_context;
     let agari_type = input.agari_type;
 
-    let organizations = organize_hand(input)?;
+    debug_log!(
+        "scoring {} + {} ({:?}), {} open melds, {} closed kans",
+        notation::to_notation(&input.hand_tiles),
+        notation::to_notation(&[input.winning_tile]),
+        agari_type,
+        input.open_melds.len(),
+        input.closed_kans.len()
+    );
+
+    input_validator::validate_input(input)?;
+    let (organizations, meta) = organize_hand_with_meta(input)?;
+    input_validator::validate_dora_count(input, &meta)?;
 
     let mut best_result: Option<AgariResult> = None;
-    let mut best_payment = 0;
 
     for organization in organizations {
-        if let Ok(yaku_result) = check_all_yaku(organization, player, game, agari_type) {
-            let final_score = calculate_score(yaku_result, player, game, agari_type);
+        match check_all_yaku(organization, player, game, agari_type) {
+            Ok(yaku_result) => {
+                let final_score = calculate_score(yaku_result, player, game, agari_type);
+                debug_log!(
+                    "candidate {}: {:?}",
+                    trace::describe_structure(&final_score.hand_structure).join(", "),
+                    final_score.summary()
+                );
 
-            if final_score.total_payment >= best_payment {
-                best_payment = final_score.total_payment;
-                best_result = Some(final_score);
+                if best_result
+                    .as_ref()
+                    .is_none_or(|best| score_rank(&final_score) >= score_rank(best))
+                {
+                    best_result = Some(final_score);
+                }
             }
+            Err(_e) => debug_log!("candidate rejected: {}", _e),
         }
     }
 
+    if let Some(_best) = &best_result {
+        debug_log!(
+            "chose {}: {:?}",
+            trace::describe_structure(&_best.hand_structure).join(", "),
+            _best.summary()
+        );
+    }
+
+    // dora alone is not a yaku
     match best_result {
-        Some(res) => Ok(res),
-        None => Err("No valid Yaku found"),
+        Some(mut res) if has_yaku(&res) => {
+            // furiten only restricts ron, a tsumo is always allowed
+            if agari_type == AgariType::Ron && !player.discards.is_empty() {
+                let (concealed, melds) = split_input(input)?;
+                res.is_furiten =
+                    tenpai::is_furiten(&tenpai::waits(&concealed, &melds), &player.discards);
+            }
+            Ok(res)
+        }
+        Some(_) => Err(NO_YAKU),
+        None => Err(NOT_A_WINNING_HAND),
+    }
+}
+
+// for callers tracking the concealed hand as 34 counts, winning tile included
+pub fn score_from_counts(
+    counts: [u8; 34],
+    winning_index: usize,
+    melds: &[Mentsu],
+    player: &PlayerContext,
+    game: &GameContext,
+    agari_type: AgariType,
+) -> Result<AgariResult, &'static str> {
+    if counts.get(winning_index).is_none_or(|&n| n == 0) {
+        return Err("Winning tile not in hand");
     }
+    // each meld, kans included, stands for 3 of the 14 tiles
+    let num_tiles: usize = counts.iter().map(|&n| n as usize).sum();
+    if num_tiles + 3 * melds.len() != 14 {
+        return Err("Counts must hold 14 tiles less 3 per declared meld");
+    }
+
+    let mut concealed = counts_to_tiles(&counts);
+    let winning_tile = index_to_tile(winning_index);
+    let pos = concealed.iter().position(|t| *t == winning_tile).unwrap();
+    concealed.remove(pos);
+
+    let input = UserInput::from_mentsu(&concealed, winning_tile, melds, player, game, agari_type);
+    calculate_agari(&input)
+}
+
+// (ron, tsumo) for the same tiles, concealed excludes the winning tile
+pub fn score_both(
+    concealed: &[Hai],
+    winning_tile: Hai,
+    melds: &[Mentsu],
+    player: &PlayerContext,
+    game: &GameContext,
+) -> (
+    Result<AgariResult, &'static str>,
+    Result<AgariResult, &'static str>,
+) {
+    let score = |agari_type| {
+        let input =
+            UserInput::from_mentsu(concealed, winning_tile, melds, player, game, agari_type);
+        calculate_agari(&input)
+    };
+    (score(AgariType::Ron), score(AgariType::Tsumo))
+}
+
+// input checks of calculate_agari without scoring, for early feedback in UIs
+pub fn validate(input: &UserInput) -> Result<(), &'static str> {
+    input_validator::validate_input(input)?;
+    let (_, meta) = organize_hand_with_meta(input)?;
+    input_validator::validate_dora_count(input, &meta)
+}
+
+// calculate_agari plus a step-by-step account of the chosen reading
+pub fn calculate_agari_explained(
+    input: &UserInput,
+) -> Result<(AgariResult, ScoreTrace), &'static str> {
+    let result = calculate_agari(input)?;
+    let trace = trace::explain_score(&result, &input.player_context, &input.game_context);
+    Ok((result, trace))
+}
+
+fn has_yaku(result: &AgariResult) -> bool {
+    result
+        .yaku_list
+        .iter()
+        .any(|y| !matches!(y, Yaku::Dora | Yaku::UraDora | Yaku::AkaDora))
+}
+
+// readings with a yaku first, then payment, then han
+fn score_rank(result: &AgariResult) -> (bool, u32, u8) {
+    (has_yaku(result), result.total_payment, result.han)
+}
+
+fn counts_to_tiles(counts: &[u8; 34]) -> Vec<Hai> {
+    counts
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &n)| std::iter::repeat_n(index_to_tile(i), n as usize))
+        .collect()
 }
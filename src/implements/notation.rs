@@ -0,0 +1,181 @@
+use crate::implements::types::tiles::{Hai, Suhai, Suit, index_to_tile, tile_to_index};
+use std::str::FromStr;
+
+// mpsz notation: "123m456p789s11z", honors also as letters (e s w n h g r)
+pub fn parse_hand(notation: &str) -> Result<Vec<Hai>, &'static str> {
+    let mut tiles = Vec::new();
+    let mut pending: Vec<u8> = Vec::new();
+
+    for c in notation.chars() {
+        if c.is_whitespace() {
+            continue;
+        }
+
+        if let Some(d) = c.to_digit(10) {
+            pending.push(d as u8);
+            continue;
+        }
+
+        let c = c.to_ascii_lowercase();
+
+        if pending.is_empty() {
+            // 東南西北白發中
+            let index = match c {
+                'e' => 27,
+                's' => 28,
+                'w' => 29,
+                'n' => 30,
+                'h' => 31,
+                'g' => 32,
+                'r' => 33,
+                _ => return Err("Unknown tile letter"),
+            };
+            tiles.push(index_to_tile(index));
+            continue;
+        }
+
+        for number in pending.drain(..) {
+            let tile = match c {
+                'm' | 'p' | 's' => {
+                    if !(1..=9).contains(&number) {
+                        return Err("Invalid tile (number tiles must be 1-9)");
+                    }
+                    let suit = match c {
+                        'm' => Suit::Manzu,
+                        'p' => Suit::Pinzu,
+                        _ => Suit::Souzu,
+                    };
+                    Hai::Suhai(Suhai { number, suit })
+                }
+                'z' => {
+                    if !(1..=7).contains(&number) {
+                        return Err("Invalid honor (z tiles must be 1-7)");
+                    }
+                    index_to_tile(26 + number as usize)
+                }
+                _ => return Err("Unknown suit letter"),
+            };
+            tiles.push(tile);
+        }
+    }
+
+    if !pending.is_empty() {
+        return Err("Numbers without a suit letter");
+    }
+
+    Ok(tiles)
+}
+
+// a single tile in the same notation: "5p", "1z" or "e"; the error names the bad input
+impl TryFrom<&str> for Hai {
+    type Error = String;
+
+    fn try_from(notation: &str) -> Result<Self, Self::Error> {
+        match parse_hand(notation).as_deref() {
+            Ok([tile]) => Ok(*tile),
+            Ok(_) => Err(format!("Expected a single tile, got '{}'", notation)),
+            Err(e) => Err(format!("{}: '{}'", e, notation)),
+        }
+    }
+}
+
+impl FromStr for Hai {
+    type Err = String;
+
+    fn from_str(notation: &str) -> Result<Self, Self::Err> {
+        Hai::try_from(notation)
+    }
+}
+
+// inverse of parse_hand: [1m, 2m, 3m, Ton] -> "123m1z"
+pub fn to_notation(tiles: &[Hai]) -> String {
+    let mut out = String::new();
+    let mut pending_suit: Option<char> = None;
+
+    for tile in tiles {
+        let (digit, suit) = match tile {
+            Hai::Suhai(Suhai { number, suit }) => (
+                *number as usize,
+                match suit {
+                    Suit::Manzu => 'm',
+                    Suit::Pinzu => 'p',
+                    Suit::Souzu => 's',
+                },
+            ),
+            Hai::Jihai(_) => (tile_to_index(tile) - 26, 'z'),
+        };
+        if let Some(prev) = pending_suit
+            && prev != suit
+        {
+            out.push(prev);
+        }
+        out.push_str(&digit.to_string());
+        pending_suit = Some(suit);
+    }
+
+    if let Some(suit) = pending_suit {
+        out.push(suit);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implements::types::tiles::{Jihai, Kaze, Sangenpai};
+
+    #[test]
+    fn honors_parse_in_z_order() {
+        let honors = [
+            Jihai::Kaze(Kaze::Ton),
+            Jihai::Kaze(Kaze::Nan),
+            Jihai::Kaze(Kaze::Shaa),
+            Jihai::Kaze(Kaze::Pei),
+            Jihai::Sangen(Sangenpai::Haku),
+            Jihai::Sangen(Sangenpai::Hatsu),
+            Jihai::Sangen(Sangenpai::Chun),
+        ];
+        for ((z, letter), jihai) in ["1z", "2z", "3z", "4z", "5z", "6z", "7z"]
+            .into_iter()
+            .zip(["e", "s", "w", "n", "h", "g", "r"])
+            .zip(honors)
+        {
+            assert_eq!(Hai::try_from(z), Ok(Hai::Jihai(jihai)));
+            assert_eq!(letter.parse::<Hai>(), Ok(Hai::Jihai(jihai)));
+        }
+    }
+
+    #[test]
+    fn suited_tiles_parse() {
+        for (notation, number, suit) in [
+            ("1m", 1, Suit::Manzu),
+            ("5p", 5, Suit::Pinzu),
+            ("9s", 9, Suit::Souzu),
+        ] {
+            assert_eq!(
+                Hai::try_from(notation),
+                Ok(Hai::Suhai(Suhai { number, suit }))
+            );
+        }
+    }
+
+    #[test]
+    fn errors_name_the_bad_token() {
+        assert_eq!(
+            Hai::try_from("8z"),
+            Err("Invalid honor (z tiles must be 1-7): '8z'".to_string())
+        );
+        assert_eq!(
+            Hai::try_from("0p"),
+            Err("Invalid tile (number tiles must be 1-9): '0p'".to_string())
+        );
+        assert_eq!(
+            "5x".parse::<Hai>(),
+            Err("Unknown suit letter: '5x'".to_string())
+        );
+        assert_eq!(
+            Hai::try_from("12m"),
+            Err("Expected a single tile, got '12m'".to_string())
+        );
+    }
+}
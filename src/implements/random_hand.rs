@@ -0,0 +1,199 @@
+use crate::implements::calculate_agari;
+use crate::implements::types::{
+    game::{AgariType, GameContext, PlayerContext},
+    hand::MentsuType,
+    input::{OpenMeldInput, UserInput},
+    tiles::{Hai, Kaze, index_to_tile, sort_tiles, tile_to_index},
+};
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+const KAZE: [Kaze; 4] = [Kaze::Ton, Kaze::Nan, Kaze::Shaa, Kaze::Pei];
+
+// 136-tile wall
+struct Wall {
+    counts: [u8; 34],
+}
+
+impl Wall {
+    fn new() -> Self {
+        Wall { counts: [4; 34] }
+    }
+
+    fn take(&mut self, tile: &Hai, n: u8) -> bool {
+        let idx = tile_to_index(tile);
+        if self.counts[idx] < n {
+            return false;
+        }
+        self.counts[idx] -= n;
+        true
+    }
+
+    fn draw(&mut self, rng: &mut impl Rng) -> Hai {
+        let remaining: Vec<usize> = (0..34).filter(|&i| self.counts[i] > 0).collect();
+        let idx = *remaining.choose(rng).unwrap();
+        self.counts[idx] -= 1;
+        index_to_tile(idx)
+    }
+}
+
+struct RandomMeld {
+    mentsu_type: MentsuType,
+    tiles: Vec<Hai>,
+}
+
+fn random_meld(wall: &mut Wall, rng: &mut impl Rng) -> RandomMeld {
+    loop {
+        if rng.gen_bool(0.6) {
+            // Shuntsu
+            let start = rng.gen_range(0..3) * 9 + rng.gen_range(0..7);
+            let tiles: Vec<Hai> = (start..start + 3).map(index_to_tile).collect();
+            if tiles.iter().all(|t| wall.counts[tile_to_index(t)] > 0) {
+                for t in &tiles {
+                    wall.take(t, 1);
+                }
+                return RandomMeld {
+                    mentsu_type: MentsuType::Shuntsu,
+                    tiles,
+                };
+            }
+        } else {
+            // Koutsu
+            let tile = index_to_tile(rng.gen_range(0..34));
+            if wall.take(&tile, 3) {
+                return RandomMeld {
+                    mentsu_type: MentsuType::Koutsu,
+                    tiles: vec![tile; 3],
+                };
+            }
+        }
+    }
+}
+
+fn random_pair(wall: &mut Wall, rng: &mut impl Rng) -> Hai {
+    loop {
+        let tile = index_to_tile(rng.gen_range(0..34));
+        if wall.take(&tile, 2) {
+            return tile;
+        }
+    }
+}
+
+/// Builds a random winning hand drawn from a 136-tile wall.
+/// Draws are repeated until the hand scores, so it always has a yaku.
+pub fn random_winning_hand(rng: &mut impl Rng) -> UserInput {
+    loop {
+        let input = random_complete_hand(rng);
+        if calculate_agari(&input).is_ok() {
+            return input;
+        }
+    }
+}
+
+// complete shape and valid context, possibly without a yaku
+fn random_complete_hand(rng: &mut impl Rng) -> UserInput {
+    let mut wall = Wall::new();
+    let mut concealed: Vec<Hai> = Vec::with_capacity(14);
+    let mut open_melds: Vec<OpenMeldInput> = Vec::new();
+
+    let roll: f64 = rng.gen_range(0.0..1.0);
+    if roll < 0.02 {
+        // Kokushi
+        let yaochuu: Vec<Hai> = Hai::all().filter(|t| t.is_yaochuu()).collect();
+        for tile in &yaochuu {
+            wall.take(tile, 1);
+            concealed.push(*tile);
+        }
+        let extra = *yaochuu.choose(rng).unwrap();
+        wall.take(&extra, 1);
+        concealed.push(extra);
+    } else if roll < 0.12 {
+        // Chiitoitsu
+        let mut indices: Vec<usize> = (0..34).collect();
+        indices.shuffle(rng);
+        for &idx in indices.iter().take(7) {
+            let tile = index_to_tile(idx);
+            wall.take(&tile, 2);
+            concealed.extend([tile, tile]);
+        }
+    } else {
+        // 4 melds + 1 pair
+        let pair = random_pair(&mut wall, rng);
+        concealed.extend([pair, pair]);
+
+        for _ in 0..4 {
+            let meld = random_meld(&mut wall, rng);
+            if rng.gen_bool(0.25) {
+                open_melds.push(OpenMeldInput {
+                    mentsu_type: meld.mentsu_type,
+                    representative_tile: meld.tiles[0],
+                    kan_type: None,
+                    called_tile: None,
+                    called_from: None,
+                });
+            } else {
+                concealed.extend(meld.tiles);
+            }
+        }
+    }
+
+    let is_menzen = open_melds.is_empty();
+    let agari_type = if rng.gen_bool(0.5) {
+        AgariType::Tsumo
+    } else {
+        AgariType::Ron
+    };
+
+    // winning tile must come from the concealed part
+    sort_tiles(&mut concealed);
+    let winning_tile = *concealed.choose(rng).unwrap();
+    if agari_type == AgariType::Ron {
+        let pos = concealed.iter().position(|t| *t == winning_tile).unwrap();
+        concealed.remove(pos);
+    }
+
+    let jikaze = *KAZE.choose(rng).unwrap();
+    let is_riichi = is_menzen && rng.gen_bool(0.5);
+
+    UserInput {
+        hand_tiles: concealed,
+        winning_tile,
+        open_melds,
+        closed_kans: Vec::new(),
+        player_context: PlayerContext {
+            jikaze,
+            is_oya: jikaze == Kaze::Ton,
+            is_riichi,
+            is_menzen,
+            ..PlayerContext::default()
+        },
+        game_context: GameContext {
+            bakaze: *KAZE[..2].choose(rng).unwrap(),
+            kyoku: rng.gen_range(1..=4),
+            dora_indicators: vec![wall.draw(rng)],
+            uradora_indicators: if is_riichi {
+                vec![wall.draw(rng)]
+            } else {
+                Vec::new()
+            },
+            ..GameContext::default()
+        },
+        agari_type,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn every_generated_hand_scores() {
+        let mut rng = StdRng::seed_from_u64(1301);
+        for _ in 0..500 {
+            let input = random_winning_hand(&mut rng);
+            assert!(calculate_agari(&input).is_ok(), "{:?}", input);
+        }
+    }
+}
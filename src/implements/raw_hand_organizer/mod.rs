@@ -4,24 +4,87 @@ pub mod wait_analyzer;
 use self::{recursive_parser::find_all_mentsu_recursive, wait_analyzer::determine_wait_type};
 use crate::implements::types::{
     game::AgariType,
-    hand::{AgariHand, HandOrganization, Machi, Mentsu, MentsuType},
+    hand::{AgariHand, HandMeta, HandOrganization, Machi, Mentsu, MentsuType},
     input::UserInput,
-    tiles::{index_to_tile, tile_to_index},
+    rules::PlayMode,
+    tiles::{Hai, Suhai, Suit, index_to_tile, tile_to_index},
 };
 use std::convert::TryInto;
 
 pub fn organize_hand(input: &UserInput) -> Result<Vec<HandOrganization>, &'static str> {
-    let mut master_counts = [0u8; 34];
-    for tile in &input.hand_tiles {
-        master_counts[tile_to_index(tile)] += 1;
+    organize_hand_with_meta(input).map(|(organizations, _)| organizations)
+}
+
+// the readings plus the meld facts validation and scoring share
+pub fn organize_hand_with_meta(
+    input: &UserInput,
+) -> Result<(Vec<HandOrganization>, HandMeta), &'static str> {
+    let declared_tiles = input
+        .open_melds
+        .iter()
+        .map(|m| &m.representative_tile)
+        .chain(&input.closed_kans);
+    if !input
+        .hand_tiles
+        .iter()
+        .chain(std::iter::once(&input.winning_tile))
+        .chain(declared_tiles)
+        .all(|t| t.is_valid())
+    {
+        return Err("Invalid tile (number tiles must be 1-9)");
     }
 
-    if input.agari_type == AgariType::Ron {
-        master_counts[tile_to_index(&input.winning_tile)] += 1;
+    if input.game_context.rules.play_mode == PlayMode::Sanma {
+        if input
+            .open_melds
+            .iter()
+            .any(|m| m.mentsu_type == MentsuType::Shuntsu)
+        {
+            return Err("Chii is not allowed in sanma");
+        }
+        let is_removed_manzu = |t: &Hai| {
+            matches!(
+                t,
+                Hai::Suhai(Suhai {
+                    number: 2..=8,
+                    suit: Suit::Manzu
+                })
+            )
+        };
+        if input
+            .hand_tiles
+            .iter()
+            .chain(std::iter::once(&input.winning_tile))
+            .chain(input.open_melds.iter().map(|m| &m.representative_tile))
+            .chain(&input.closed_kans)
+            .any(is_removed_manzu)
+        {
+            return Err("Manzu 2-8 are not used in sanma");
+        }
+    }
+
+    if input.open_melds.len() + input.closed_kans.len() > 4 {
+        return Err("Too many melds declared (max 4)");
+    }
+
+    let (concealed, open_mentsu) = split_input(input)?;
+    let meta = HandMeta::from_melds(&open_mentsu);
+    let organizations = organize_hand_split(&concealed, input.winning_tile, &open_mentsu)?;
+    Ok((organizations, meta))
+}
+
+// concealed tiles without the winning tile, and the declared melds as Mentsu
+pub(crate) fn split_input(input: &UserInput) -> Result<(Vec<Hai>, Vec<Mentsu>), &'static str> {
+    // Tsumo input carries the winning tile in hand_tiles, Ron input doesn't
+    let mut concealed = input.hand_tiles.clone();
+    if input.agari_type == AgariType::Tsumo {
+        let pos = concealed
+            .iter()
+            .position(|t| *t == input.winning_tile)
+            .ok_or("Winning tile not in hand")?;
+        concealed.remove(pos);
     }
 
-    let concealed_counts = master_counts;
-    let mut final_results: Vec<HandOrganization> = Vec::new();
     let mut open_mentsu: Vec<Mentsu> = Vec::with_capacity(4);
 
     // Closed Kans
@@ -75,35 +138,61 @@ pub fn organize_hand(input: &UserInput) -> Result<Vec<HandOrganization>, &'stati
         }
     }
 
+    Ok((concealed, open_mentsu))
+}
+
+// concealed excludes the winning tile; melds are the declared open melds and closed kans
+pub fn organize_hand_split(
+    concealed: &[Hai],
+    winning: Hai,
+    melds: &[Mentsu],
+) -> Result<Vec<HandOrganization>, &'static str> {
+    if !concealed
+        .iter()
+        .chain(std::iter::once(&winning))
+        .chain(melds.iter().flat_map(Mentsu::tiles_slice))
+        .all(|t| t.is_valid())
+    {
+        return Err("Invalid tile (number tiles must be 1-9)");
+    }
+    if melds.len() > 4 {
+        return Err("Too many melds declared (max 4)");
+    }
+
+    let mut concealed_tiles = concealed.to_vec();
+    concealed_tiles.push(winning);
+
+    let mut concealed_counts = [0u8; 34];
+    for tile in &concealed_tiles {
+        concealed_counts[tile_to_index(tile)] += 1;
+    }
+
+    let mut final_results: Vec<HandOrganization> = Vec::new();
+    let open_mentsu = melds.to_vec();
     let mentsu_needed = 4 - open_mentsu.len();
-    let agari_hai = input.winning_tile;
+    let agari_hai = winning;
 
-    // 4 known melds
+    // 4 known melds: hadaka tanki, only the pair is left in hand
     if mentsu_needed == 0 {
-        for i in 0..34 {
-            if concealed_counts[i] == 2 {
-                let pair_tile = index_to_tile(i);
-                let atama = (pair_tile, pair_tile);
-
-                let mentsu_array: [Mentsu; 4] = open_mentsu
-                    .clone()
-                    .try_into()
-                    .map_err(|_| "final_mentsu length not 4")?;
-
-                let agari_hand = AgariHand {
-                    mentsu: mentsu_array,
-                    atama,
-                    agari_hai,
-                    machi: Machi::Tanki,
-                };
-
-                final_results.push(HandOrganization::YonmentsuIchiatama(agari_hand));
-            }
-        }
-        if input.hand_tiles.len() == 14 {
-        } else if final_results.is_empty() {
-            return Err("4 open melds but no pair found");
-        }
+        let pair_index = concealed_counts.iter().position(|&count| count == 2);
+        let Some(i) = pair_index.filter(|_| concealed_tiles.len() == 2) else {
+            return Err("4 declared melds need exactly one pair in hand");
+        };
+
+        let pair_tile = index_to_tile(i);
+        let mentsu_array: [Mentsu; 4] = open_mentsu
+            .clone()
+            .try_into()
+            .map_err(|_| "final_mentsu length not 4")?;
+
+        let agari_hand = AgariHand {
+            mentsu: mentsu_array,
+            atama: (pair_tile, pair_tile),
+            agari_hai,
+            machi: Machi::Tanki,
+        };
+
+        final_results.push(HandOrganization::YonmentsuIchiatama(agari_hand));
     } else {
         // Standard Hand
         for i in 0..34 {
@@ -129,6 +218,7 @@ pub fn organize_hand(input: &UserInput) -> Result<Vec<HandOrganization>, &'stati
                             .try_into()
                             .map_err(|_| "final_mentsu length not 4")?;
 
+                        // no wait means the winning tile isn't in this decomposition
                         let possible_waits = determine_wait_type(&mentsu_array, atama, agari_hai);
 
                         for machi in possible_waits {
@@ -147,10 +237,10 @@ pub fn organize_hand(input: &UserInput) -> Result<Vec<HandOrganization>, &'stati
         }
     }
 
-    final_results.push(HandOrganization::Irregular {
-        counts: master_counts,
+    final_results.push(HandOrganization::irregular_from_tiles(
+        &concealed_tiles,
         agari_hai,
-    });
+    ));
 
     Ok(final_results)
 }
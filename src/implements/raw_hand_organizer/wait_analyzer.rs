@@ -1,6 +1,6 @@
 use crate::implements::types::{
     hand::{Machi, Mentsu, MentsuType},
-    tiles::{Hai, tile_to_index},
+    tiles::{Hai, Suhai},
 };
 
 fn mentsu_contains_tile(mentsu: &Mentsu, tile: &Hai) -> bool {
@@ -12,6 +12,8 @@ fn mentsu_contains_tile(mentsu: &Mentsu, tile: &Hai) -> bool {
     }
 }
 
+/// Returns every wait the winning tile could have completed.
+/// Empty if the winning tile is not in the pair or a concealed meld.
 pub fn determine_wait_type(mentsu: &[Mentsu; 4], atama: (Hai, Hai), agari_hai: Hai) -> Vec<Machi> {
     let mut possible_waits = Vec::new();
 
@@ -19,8 +21,10 @@ pub fn determine_wait_type(mentsu: &[Mentsu; 4], atama: (Hai, Hai), agari_hai: H
         possible_waits.push(Machi::Tanki);
     }
 
+    // called melds and kans can't be completed by the winning tile
     let winning_melds: Vec<&Mentsu> = mentsu
         .iter()
+        .filter(|m| !m.is_minchou && m.mentsu_type != MentsuType::Kantsu)
         .filter(|m| mentsu_contains_tile(m, &agari_hai))
         .collect();
 
@@ -32,16 +36,25 @@ pub fn determine_wait_type(mentsu: &[Mentsu; 4], atama: (Hai, Hai), agari_hai: H
                 let t2 = winning_meld.tiles[1];
                 let t3 = winning_meld.tiles[2];
 
+                // honors never form a shuntsu
+                let (Hai::Suhai(Suhai { number: low, .. }), Hai::Suhai(Suhai { number: high, .. })) =
+                    (t1, t3)
+                else {
+                    continue;
+                };
+
                 if agari_hai == t2 {
                     Machi::Kanchan
                 } else if agari_hai == t1 {
-                    if tile_to_index(&t3) % 9 == 8 {
+                    // 7 on 789
+                    if high == 9 {
                         Machi::Penchan
                     } else {
                         Machi::Ryanmen
                     }
                 } else if agari_hai == t3 {
-                    if tile_to_index(&t1) % 9 == 0 {
+                    // 3 on 123
+                    if low == 1 {
                         Machi::Penchan
                     } else {
                         Machi::Ryanmen
@@ -55,3 +68,81 @@ pub fn determine_wait_type(mentsu: &[Mentsu; 4], atama: (Hai, Hai), agari_hai: H
     }
     possible_waits
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implements::notation::parse_hand;
+
+    fn meld(notation: &str, is_minchou: bool) -> Mentsu {
+        let tiles = parse_hand(notation).unwrap();
+        let mentsu_type = match tiles.len() {
+            4 => MentsuType::Kantsu,
+            _ if tiles[0] == tiles[1] => MentsuType::Koutsu,
+            _ => MentsuType::Shuntsu,
+        };
+        Mentsu {
+            mentsu_type,
+            is_minchou,
+            tiles: [tiles[0], tiles[1], tiles[2], *tiles.last().unwrap()],
+        }
+    }
+
+    fn closed(notations: [&str; 4]) -> [Mentsu; 4] {
+        notations.map(|n| meld(n, false))
+    }
+
+    fn pair(notation: &str) -> (Hai, Hai) {
+        let tile = Hai::try_from(notation).unwrap();
+        (tile, tile)
+    }
+
+    fn tile(notation: &str) -> Hai {
+        Hai::try_from(notation).unwrap()
+    }
+
+    #[test]
+    fn winning_tile_outside_the_hand_has_no_wait() {
+        let mentsu = closed(["123m", "456p", "789s", "111z"]);
+        assert_eq!(determine_wait_type(&mentsu, pair("9m"), tile("5s")), vec![]);
+    }
+
+    #[test]
+    fn called_melds_are_never_the_wait() {
+        let mut mentsu = closed(["123m", "456p", "789s", "555z"]);
+        mentsu[3] = meld("555z", true);
+        assert_eq!(determine_wait_type(&mentsu, pair("9m"), tile("5z")), vec![]);
+
+        mentsu[3] = meld("5555z", false);
+        assert_eq!(determine_wait_type(&mentsu, pair("9m"), tile("5z")), vec![]);
+    }
+
+    // edge waits are judged by suit number
+    #[test]
+    fn sequence_waits_by_position() {
+        let waits = |run: &str, win: &str| {
+            let mentsu = closed([run, "111z", "222z", "333z"]);
+            determine_wait_type(&mentsu, pair("9p"), tile(win))
+        };
+        assert_eq!(waits("123m", "3m"), vec![Machi::Penchan]);
+        assert_eq!(waits("789s", "7s"), vec![Machi::Penchan]);
+        assert_eq!(waits("234p", "4p"), vec![Machi::Ryanmen]);
+        assert_eq!(waits("234p", "2p"), vec![Machi::Ryanmen]);
+        assert_eq!(waits("456m", "5m"), vec![Machi::Kanchan]);
+        assert_eq!(waits("123m", "1m"), vec![Machi::Ryanmen]);
+        assert_eq!(waits("789s", "9s"), vec![Machi::Ryanmen]);
+    }
+
+    #[test]
+    fn pair_and_triplet_waits() {
+        let mentsu = closed(["123m", "456p", "789s", "999m"]);
+        assert_eq!(
+            determine_wait_type(&mentsu, pair("1z"), tile("1z")),
+            vec![Machi::Tanki]
+        );
+        assert_eq!(
+            determine_wait_type(&mentsu, pair("1z"), tile("9m")),
+            vec![Machi::Shanpon]
+        );
+    }
+}
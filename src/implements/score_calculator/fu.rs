@@ -12,73 +12,104 @@ pub fn calculate_fu(
     game: &GameContext,
     agari_type: AgariType,
 ) -> u8 {
-    // Chiitoitsu
-    if yaku_list.contains(&Yaku::Chiitoitsu) {
-        return 25;
-    }
+    let fu: u32 = fu_breakdown(hand_structure, yaku_list, player, game, agari_type)
+        .iter()
+        .map(|(_, fu)| fu)
+        .sum();
 
-    // Pinfu
-    if yaku_list.contains(&Yaku::Pinfu) {
-        return if agari_type == AgariType::Tsumo {
-            20
-        } else {
-            30
-        };
+    // Chiitoitsu: fixed 25, no rounding
+    if let HandStructure::Chiitoitsu { .. } = hand_structure {
+        return fu as u8;
     }
 
-    // Standard Fu
-    let mut fu = 20;
+    (fu.div_ceil(10) * 10) as u8
+}
 
+// itemised fu before rounding, shared by calculate_fu and the score trace
+pub fn fu_breakdown(
+    hand_structure: &HandStructure,
+    yaku_list: &[Yaku],
+    player: &PlayerContext,
+    game: &GameContext,
+    agari_type: AgariType,
+) -> Vec<(&'static str, u32)> {
     let hand = match hand_structure {
         HandStructure::YonmentsuIchiatama(h) => h,
         HandStructure::ChuurenPoutou { hand, .. } => hand,
-        HandStructure::Chiitoitsu { .. } => return 25,
-        HandStructure::KokushiMusou { .. } => return 0,
+        // Chiitoitsu: fixed 25, no wait fu
+        HandStructure::Chiitoitsu { .. } => return vec![("Chiitoitsu", 25)],
+        HandStructure::KokushiMusou { .. } => return vec![],
     };
 
+    // Standard Fu
+    let mut items = vec![("Base", 20)];
+
+    // Pinfu
+    if yaku_list.contains(&Yaku::Pinfu) {
+        if agari_type == AgariType::Ron {
+            items.push(("Menzen ron", 10));
+        }
+        return items;
+    }
+
     // Agari Type
     if agari_type == AgariType::Tsumo {
-        fu += 2; // Tsumo fu
+        items.push(("Tsumo", 2));
     } else if player.is_menzen {
-        fu += 10; // Menzen Ron fu
+        items.push(("Menzen ron", 10));
     }
 
     // Melds
     for mentsu in &hand.mentsu {
-        let is_open = mentsu.is_minchou;
+        // a triplet completed by ron on a shanpon wait scores as open
+        let is_open = mentsu.is_minchou
+            || (agari_type == AgariType::Ron
+                && hand.machi == Machi::Shanpon
+                && mentsu.mentsu_type == MentsuType::Koutsu
+                && mentsu.tiles[0] == hand.agari_hai);
         let is_yaochuu = mentsu.tiles[0].is_yaochuu();
 
         match mentsu.mentsu_type {
             MentsuType::Koutsu => {
-                fu += match (is_open, is_yaochuu) {
-                    (true, false) => 2,  // Open simple triplet
-                    (true, true) => 4,   // Open terminal/honor triplet
-                    (false, false) => 4, // Concealed simple triplet
-                    (false, true) => 8,  // Concealed terminal/honor triplet
-                };
+                items.push(match (is_open, is_yaochuu) {
+                    (true, false) => ("Open simple triplet", 2),
+                    (true, true) => ("Open terminal/honor triplet", 4),
+                    (false, false) => ("Concealed simple triplet", 4),
+                    (false, true) => ("Concealed terminal/honor triplet", 8),
+                });
             }
             MentsuType::Kantsu => {
-                fu += match (is_open, is_yaochuu) {
-                    (true, false) => 8,   // Open simple quad
-                    (true, true) => 16,   // Open terminal/honor quad
-                    (false, false) => 16, // Concealed simple quad
-                    (false, true) => 32,  // Concealed terminal/honor quad
-                };
+                items.push(match (is_open, is_yaochuu) {
+                    (true, false) => ("Open simple quad", 8),
+                    (true, true) => ("Open terminal/honor quad", 16),
+                    (false, false) => ("Concealed simple quad", 16),
+                    (false, true) => ("Concealed terminal/honor quad", 32),
+                });
             }
             MentsuType::Shuntsu => {}
         }
     }
 
     // Pair
-    fu += get_pair_fu(&hand.atama.0, player, game);
+    let pair_fu = get_pair_fu(&hand.atama.0, player, game);
+    if pair_fu > 0 {
+        items.push(("Yakuhai pair", pair_fu));
+    }
 
     // Wait
     match hand.machi {
-        Machi::Kanchan | Machi::Penchan | Machi::Tanki => fu += 2,
+        Machi::Kanchan => items.push(("Kanchan wait", 2)),
+        Machi::Penchan => items.push(("Penchan wait", 2)),
+        Machi::Tanki => items.push(("Tanki wait", 2)),
         _ => {}
     }
 
-    (((fu + 9) / 10) * 10) as u8
+    // 喰い平和: an open pinfu shape won by ron has only the base, it counts as 30
+    if items.len() == 1 {
+        items.push(("Open pinfu shape", 10));
+    }
+
+    items
 }
 
 fn get_pair_fu(tile: &Hai, player: &PlayerContext, game: &GameContext) -> u32 {
@@ -7,7 +7,7 @@ pub fn calculate_han(yaku_list: &[Yaku], is_menzen: bool) -> u8 {
         .sum()
 }
 
-fn get_han_value(yaku: &Yaku, is_menzen: bool) -> u8 {
+pub fn get_han_value(yaku: &Yaku, is_menzen: bool) -> u8 {
     match yaku {
         // 1 Han
         Yaku::Riichi => 1,
@@ -1,7 +1,8 @@
 pub mod fu;
 pub mod han;
 pub mod points;
-pub mod yakuman;
+pub mod trace;
+mod yakuman;
 
 use self::{
     fu::calculate_fu,
@@ -12,7 +13,8 @@ use self::{
 use super::YakuResult;
 use crate::implements::types::{
     game::{AgariType, GameContext, PlayerContext},
-    scoring::{AgariResult, HandLimit},
+    scoring::{AgariResult, HandLimit, ScoredHand},
+    yaku::Yaku,
 };
 
 pub fn calculate_score(
@@ -23,57 +25,86 @@ pub fn calculate_score(
 ) -> AgariResult {
     let tsumo_bonus = game.honba as u32 * 100;
     let ron_bonus = game.honba as u32 * 300;
+    // sanma tsumo: the missing seat's share is simply not paid
+    let tsumo_payers = game.rules.play_mode.num_tsumo_payers();
     let yaku_list = yaku_result.yaku_list;
     let num_akadora = yaku_result.num_akadora;
 
-    // Check Yakuman
-    let num_yakuman = count_yakuman(&yaku_list);
+    // Check Yakuman, a cap still leaves at least one
+    let num_yakuman = match game.rules.max_yakuman {
+        Some(cap) => count_yakuman(&yaku_list).min(cap.max(1)),
+        None => count_yakuman(&yaku_list),
+    };
 
     if num_yakuman > 0 {
         let han = 13 * num_yakuman as u8;
         let fu = 0;
         let limit_name = Some(HandLimit::Yakuman);
-        let base_yakuman_points = (8000 * num_yakuman) as u32;
+        let yakuman_base = HandLimit::Yakuman.base_points();
+
+        // Sekinin-barai: the liable player covers Daisangen/Daisuushi
+        let pao_yakuman = if game.pao_player.is_some() {
+            yaku_list
+                .iter()
+                .filter(|y| matches!(y, Yaku::Daisangen | Yaku::Daisuushi))
+                .count() as u32
+        } else {
+            0
+        }
+        .min(num_yakuman);
+        let pao_value = if player.is_oya {
+            HandLimit::Yakuman.dealer_ron() * pao_yakuman
+        } else {
+            HandLimit::Yakuman.nondealer_ron() * pao_yakuman
+        };
+
+        let hand = ScoredHand {
+            han,
+            fu,
+            yaku_list,
+            num_akadora: 0,
+            limit_name,
+            honba: game.honba,
+            riichi_sticks: game.riichi_sticks,
+            is_oya: player.is_oya,
+            hand_structure: yaku_result.hand_structure,
+            dora_breakdown: yaku_result.dora_breakdown,
+        };
 
-        let (oya_payment, ko_payment, total_payment) = match (player.is_oya, agari_type) {
+        return match (player.is_oya, agari_type) {
+            // Tsumo: pao pays its yakuman in full, the rest is shared as usual
+            (_, AgariType::Tsumo) if num_yakuman == pao_yakuman => {
+                let total = pao_value + ron_bonus;
+                AgariResult::tsumo(hand, 0, 0, total, total)
+            }
             // Oya Tsumo
             (true, AgariType::Tsumo) => {
-                let p = round_up_100(base_yakuman_points * 2);
-                let total = (p + tsumo_bonus) * 3;
-                (p, 0, total)
+                let shared_points = yakuman_base * (num_yakuman - pao_yakuman);
+                let p = round_up_100(shared_points * 2);
+                let total = (p + tsumo_bonus) * tsumo_payers + pao_value;
+                AgariResult::tsumo(hand, p, 0, total, pao_value)
             }
             // Ko Tsumo
             (false, AgariType::Tsumo) => {
-                let oya_p = round_up_100(base_yakuman_points * 2);
-                let ko_p = round_up_100(base_yakuman_points * 1);
-                let total = (oya_p + tsumo_bonus) + (ko_p + tsumo_bonus) * 2;
-                (oya_p, ko_p, total)
+                let shared_points = yakuman_base * (num_yakuman - pao_yakuman);
+                let oya_p = round_up_100(shared_points * 2);
+                let ko_p = round_up_100(shared_points);
+                let total =
+                    (oya_p + tsumo_bonus) + (ko_p + tsumo_bonus) * (tsumo_payers - 1) + pao_value;
+                AgariResult::tsumo(hand, oya_p, ko_p, total, pao_value)
             }
+            // Ron: pao and discarder split the pao yakuman
             // Oya Ron
             (true, AgariType::Ron) => {
-                let total = round_up_100(base_yakuman_points * 6) + ron_bonus;
-                (0, 0, total)
+                let total = round_up_100(yakuman_base * num_yakuman * 6) + ron_bonus;
+                AgariResult::ron(hand, total, pao_value / 2)
             }
             // Ko Ron
             (false, AgariType::Ron) => {
-                let total = round_up_100(base_yakuman_points * 4) + ron_bonus;
-                (0, 0, total)
+                let total = round_up_100(yakuman_base * num_yakuman * 4) + ron_bonus;
+                AgariResult::ron(hand, total, pao_value / 2)
             }
         };
-
-        return AgariResult {
-            han,
-            fu,
-            yaku_list,
-            num_akadora: 0,
-            limit_name,
-            oya_payment,
-            ko_payment,
-            total_payment,
-            honba: game.honba,
-            agari_type,
-            is_oya: player.is_oya,
-        };
     }
 
     // Regular Hand
@@ -86,45 +117,50 @@ pub fn calculate_score(
         agari_type,
     );
 
-    let (basic_points, limit_name) = calculate_basic_points(han, fu);
+    let (basic_points, limit_name) = calculate_basic_points(han, fu, game.rules.kiriage_mangan);
+
+    let hand = ScoredHand {
+        han,
+        fu,
+        yaku_list,
+        num_akadora,
+        limit_name: limit_name.clone(),
+        honba: game.honba,
+        riichi_sticks: game.riichi_sticks,
+        is_oya: player.is_oya,
+        hand_structure: yaku_result.hand_structure,
+        dora_breakdown: yaku_result.dora_breakdown,
+    };
 
-    let (oya_payment, ko_payment, total_payment) = match (player.is_oya, agari_type) {
+    match (player.is_oya, agari_type) {
         // Oya Tsumo
         (true, AgariType::Tsumo) => {
             let p = round_up_100(basic_points * 2);
-            let total = (p + tsumo_bonus) * 3;
-            (p, 0, total)
+            let total = (p + tsumo_bonus) * tsumo_payers;
+            AgariResult::tsumo(hand, p, 0, total, 0)
         }
         // Ko Tsumo
         (false, AgariType::Tsumo) => {
             let oya_p = round_up_100(basic_points * 2);
-            let ko_p = round_up_100(basic_points * 1);
-            let total = (oya_p + tsumo_bonus) + (ko_p + tsumo_bonus) * 2;
-            (oya_p, ko_p, total)
+            let ko_p = round_up_100(basic_points);
+            let total = (oya_p + tsumo_bonus) + (ko_p + tsumo_bonus) * (tsumo_payers - 1);
+            AgariResult::tsumo(hand, oya_p, ko_p, total, 0)
         }
         // Oya Ron
         (true, AgariType::Ron) => {
-            let total = round_up_100(basic_points * 6) + ron_bonus;
-            (0, 0, total)
+            let points = match &limit_name {
+                Some(limit) => limit.dealer_ron(),
+                None => round_up_100(basic_points * 6),
+            };
+            AgariResult::ron(hand, points + ron_bonus, 0)
         }
         // Ko Ron
         (false, AgariType::Ron) => {
-            let total = round_up_100(basic_points * 4) + ron_bonus;
-            (0, 0, total)
+            let points = match &limit_name {
+                Some(limit) => limit.nondealer_ron(),
+                None => round_up_100(basic_points * 4),
+            };
+            AgariResult::ron(hand, points + ron_bonus, 0)
         }
-    };
-
-    AgariResult {
-        han,
-        fu,
-        yaku_list,
-        num_akadora,
-        limit_name,
-        oya_payment,
-        ko_payment,
-        total_payment,
-        honba: game.honba,
-        agari_type,
-        is_oya: player.is_oya,
     }
 }
@@ -1,33 +1,70 @@
 use crate::implements::types::scoring::HandLimit;
 
-pub fn calculate_basic_points(han: u8, fu: u8) -> (u32, Option<HandLimit>) {
-    if han >= 13 {
-        return (8000, Some(HandLimit::Yakuman));
-    }
-    if han >= 11 {
-        return (6000, Some(HandLimit::Sanbaiman));
-    }
-    if han >= 8 {
-        return (4000, Some(HandLimit::Baiman));
-    }
-    if han >= 6 {
-        return (3000, Some(HandLimit::Haneman));
-    }
-    if han == 5 {
-        return (2000, Some(HandLimit::Mangan));
+// kiriage rounds 1920 (4 han 30 fu, 3 han 60 fu) up to Mangan
+pub fn calculate_basic_points(han: u8, fu: u8, kiriage: bool) -> (u32, Option<HandLimit>) {
+    let limit = if han >= 13 {
+        Some(HandLimit::KazoeYakuman)
+    } else if han >= 11 {
+        Some(HandLimit::Sanbaiman)
+    } else if han >= 8 {
+        Some(HandLimit::Baiman)
+    } else if han >= 6 {
+        Some(HandLimit::Haneman)
+    } else if han == 5 {
+        Some(HandLimit::Mangan)
+    } else {
+        None
+    };
+    if let Some(limit) = limit {
+        return (limit.base_points(), Some(limit));
     }
 
     // Below Mangan
     let basic_points = (fu as u32) * (1 << (han + 2));
 
-    // kiriage Mangan
-    if basic_points >= 2000 {
-        (2000, Some(HandLimit::Mangan))
+    // capped at Mangan: 2000 and above always, 1920 only with kiriage
+    let threshold = if kiriage {
+        1920
+    } else {
+        HandLimit::Mangan.base_points()
+    };
+    if basic_points >= threshold {
+        (HandLimit::Mangan.base_points(), Some(HandLimit::Mangan))
     } else {
         (basic_points, None)
     }
 }
 
 pub fn round_up_100(n: u32) -> u32 {
-    (n + 99) / 100 * 100
+    n.div_ceil(100) * 100
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANGAN: (u32, Option<HandLimit>) = (2000, Some(HandLimit::Mangan));
+
+    #[test]
+    fn base_1920_needs_kiriage() {
+        assert_eq!(calculate_basic_points(4, 30, false), (1920, None));
+        assert_eq!(calculate_basic_points(3, 60, false), (1920, None));
+        assert_eq!(calculate_basic_points(4, 30, true), MANGAN);
+        assert_eq!(calculate_basic_points(3, 60, true), MANGAN);
+    }
+
+    #[test]
+    fn base_2000_is_mangan() {
+        // no real han/fu lands on 2000 exactly, 125 fu only pins the boundary
+        assert_eq!(calculate_basic_points(2, 125, false), MANGAN);
+        assert_eq!(calculate_basic_points(2, 124, false), (1984, None));
+    }
+
+    #[test]
+    fn above_2000_is_mangan_either_way() {
+        for kiriage in [false, true] {
+            assert_eq!(calculate_basic_points(3, 70, kiriage), MANGAN);
+            assert_eq!(calculate_basic_points(4, 40, kiriage), MANGAN);
+        }
+    }
 }
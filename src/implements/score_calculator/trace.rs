@@ -0,0 +1,203 @@
+use super::{fu::fu_breakdown, han::get_han_value, points::calculate_basic_points};
+use crate::implements::notation::to_notation;
+use crate::implements::types::{
+    game::{AgariType, GameContext, PlayerContext},
+    hand::{AgariHand, HandStructure},
+    scoring::{AgariResult, HandLimit, ScoreTrace},
+    tiles::sort_tiles,
+    yaku::Yaku,
+};
+
+// replays the scoring of a finished result step by step
+pub fn explain_score(
+    result: &AgariResult,
+    player: &PlayerContext,
+    game: &GameContext,
+) -> ScoreTrace {
+    let mut steps = describe_structure(&result.hand_structure);
+    let is_yakuman = result.limit_name == Some(HandLimit::Yakuman);
+
+    // Yaku
+    let mut dora_han = 0;
+    for yaku in &result.yaku_list {
+        match yaku {
+            Yaku::Dora | Yaku::UraDora | Yaku::AkaDora => dora_han += 1,
+            _ if is_yakuman => steps.push(format!("{:?}: yakuman", yaku)),
+            _ => steps.push(format!(
+                "{:?}: {} han",
+                yaku,
+                get_han_value(yaku, player.is_menzen)
+            )),
+        }
+    }
+    if dora_han > 0 {
+        steps.push(format!("Dora: {} han", dora_han));
+    }
+
+    if is_yakuman {
+        let num_yakuman = (result.han / 13) as u32;
+        steps.push(format!(
+            "Basic points: {} x {} = {}",
+            num_yakuman,
+            HandLimit::Yakuman.base_points(),
+            num_yakuman * HandLimit::Yakuman.base_points()
+        ));
+        explain_payment(&mut steps, result, None);
+        return ScoreTrace { steps };
+    }
+
+    steps.push(format!("Han: {}", result.han));
+
+    // Fu
+    let items = fu_breakdown(
+        &result.hand_structure,
+        &result.yaku_list,
+        player,
+        game,
+        result.agari_type,
+    );
+    for (label, fu) in &items {
+        steps.push(format!("{}: {} fu", label, fu));
+    }
+    let raw_fu: u32 = items.iter().map(|(_, fu)| fu).sum();
+    if raw_fu == result.fu as u32 {
+        steps.push(format!("Fu: {}", result.fu));
+    } else {
+        steps.push(format!("Fu: {}, rounded up to {}", raw_fu, result.fu));
+    }
+
+    // Basic points
+    let (basic_points, limit) =
+        calculate_basic_points(result.han, result.fu, game.rules.kiriage_mangan);
+    match limit {
+        Some(limit) => steps.push(format!("Basic points: {} = {}", limit.name(), basic_points)),
+        None => steps.push(format!(
+            "Basic points: {} x 2^({} + 2) = {}",
+            result.fu, result.han, basic_points
+        )),
+    }
+
+    explain_payment(&mut steps, result, Some(basic_points));
+    ScoreTrace { steps }
+}
+
+pub(crate) fn describe_structure(hand_structure: &HandStructure) -> Vec<String> {
+    match hand_structure {
+        HandStructure::YonmentsuIchiatama(hand) | HandStructure::ChuurenPoutou { hand, .. } => {
+            describe_agari_hand(hand)
+        }
+        HandStructure::Chiitoitsu {
+            pairs,
+            agari_hai,
+            machi,
+        } => {
+            let groups: Vec<String> = pairs.iter().map(|(a, b)| to_notation(&[*a, *b])).collect();
+            vec![
+                format!("Hand: {}", groups.join(" ")),
+                format!("Wait: {:?} on {}", machi, to_notation(&[*agari_hai])),
+            ]
+        }
+        HandStructure::KokushiMusou {
+            _agari_hai: agari_hai,
+            _machi: machi,
+            ..
+        } => {
+            let mut all_tiles = hand_structure.tiles();
+            sort_tiles(&mut all_tiles);
+            vec![
+                format!("Hand: {}", to_notation(&all_tiles)),
+                format!("Wait: {:?} on {}", machi, to_notation(&[*agari_hai])),
+            ]
+        }
+    }
+}
+
+fn describe_agari_hand(hand: &AgariHand) -> Vec<String> {
+    let mut groups: Vec<String> = hand
+        .mentsu
+        .iter()
+        .map(|m| {
+            let open = if m.is_minchou { " (open)" } else { "" };
+            format!("{}{}", to_notation(m.tiles_slice()), open)
+        })
+        .collect();
+    groups.push(to_notation(&[hand.atama.0, hand.atama.1]));
+
+    vec![
+        format!("Hand: {}", groups.join(" ")),
+        format!(
+            "Wait: {:?} on {}",
+            hand.machi,
+            to_notation(&[hand.agari_hai])
+        ),
+    ]
+}
+
+// basic_points is None when the payments can't be derived from one base (yakuman)
+fn explain_payment(steps: &mut Vec<String>, result: &AgariResult, basic_points: Option<u32>) {
+    match (result.agari_type, result.is_oya, basic_points) {
+        (AgariType::Ron, is_oya, Some(bp)) => {
+            let factor = if is_oya { 6 } else { 4 };
+            steps.push(format!(
+                "Ron: {} x {} = {}, rounded up to {}",
+                bp,
+                factor,
+                bp * factor,
+                result.total_payment - result.honba as u32 * 300
+            ));
+        }
+        (AgariType::Tsumo, true, Some(bp)) => steps.push(format!(
+            "Each non-dealer pays {} x 2 = {}, rounded up to {}",
+            bp,
+            bp * 2,
+            result.oya_payment
+        )),
+        (AgariType::Tsumo, false, Some(bp)) => {
+            steps.push(format!(
+                "Dealer pays {} x 2 = {}, rounded up to {}",
+                bp,
+                bp * 2,
+                result.oya_payment
+            ));
+            steps.push(format!(
+                "Each non-dealer pays {}, rounded up to {}",
+                bp, result.ko_payment
+            ));
+        }
+        // pao covers the whole tsumo
+        (AgariType::Tsumo, _, None) if result.oya_payment == 0 => {}
+        (AgariType::Ron, _, None) => steps.push(format!(
+            "Ron: {}",
+            result.total_payment - result.honba as u32 * 300
+        )),
+        (AgariType::Tsumo, true, None) => {
+            steps.push(format!("Each non-dealer pays {}", result.oya_payment))
+        }
+        (AgariType::Tsumo, false, None) => steps.push(format!(
+            "Dealer pays {}, each non-dealer pays {}",
+            result.oya_payment, result.ko_payment
+        )),
+    }
+
+    if result.honba > 0 {
+        steps.push(format!(
+            "Honba: {} x 300 = {}",
+            result.honba,
+            result.honba as u32 * 300
+        ));
+    }
+    if result.riichi_sticks > 0 {
+        steps.push(format!(
+            "Riichi sticks: {} x 1000 = {}",
+            result.riichi_sticks,
+            result.riichi_bonus()
+        ));
+    }
+    if result.pao_payment > 0 {
+        steps.push(format!(
+            "Pao: {} from the liable player",
+            result.pao_payment
+        ));
+    }
+    steps.push(format!("Total: {}", result.total_payment));
+}
@@ -22,6 +22,9 @@ pub fn count_yakuman(yaku_list: &[Yaku]) -> u32 {
             Yaku::Suukantsu => 1,
             Yaku::KokushiMusou => 1,
             Yaku::ChuurenPoutou => 1,
+            Yaku::Daisharin => 1,
+            Yaku::Daichikurin => 1,
+            Yaku::Daisuurin => 1,
             _ => 0,
         })
         .sum()
@@ -0,0 +1,345 @@
+use crate::implements::types::{
+    game::{AgariType, GameContext, PlayerContext, seat_wind},
+    hand::{KanType, MentsuType},
+    input::{OpenMeldInput, UserInput},
+    tiles::{Hai, Kaze, index_to_tile},
+};
+use serde_json::Value;
+
+const BAD_LOG: &str = "Malformed Tenhou log";
+
+// One round of a tenhou.net/6 JSON log (or a whole log, whose first agari is
+// taken) to the winner's UserInput. The winner's hand is rebuilt from their
+// haipai, draws, calls and discards; situational flags come from the logged
+// yaku names. Yonma only, the first winner of a double ron.
+pub fn from_tenhou_agari(json: &str) -> Result<UserInput, &'static str> {
+    let log: Value = serde_json::from_str(json).map_err(|_| "Invalid Tenhou log JSON")?;
+    let round = match &log {
+        Value::Object(game) => game
+            .get("log")
+            .and_then(Value::as_array)
+            .ok_or(BAD_LOG)?
+            .iter()
+            .find(|round| agari_entry(round).is_some())
+            .ok_or("Log has no agari")?,
+        Value::Array(_) => &log,
+        _ => return Err(BAD_LOG),
+    };
+    let entry = agari_entry(round).ok_or("Round did not end in an agari")?;
+    let round = round.as_array().ok_or(BAD_LOG)?;
+
+    let header = codes(round.first())?;
+    let [index, honba, riichi_sticks] = header[..] else {
+        return Err(BAD_LOG);
+    };
+    let (who, from_who, pao_who) = match entry.get(..3) {
+        Some([w, f, p]) => (player(w)?, player(f)?, player(p)?),
+        _ => return Err(BAD_LOG),
+    };
+    let yaku: Vec<&str> = entry.iter().skip(4).filter_map(Value::as_str).collect();
+    let has = |name: &str| yaku.iter().any(|y| y.starts_with(name));
+
+    const WINDS: [Kaze; 4] = [Kaze::Ton, Kaze::Nan, Kaze::Shaa, Kaze::Pei];
+    let kyoku = index % 4 + 1;
+    let agari_type = if who == from_who {
+        AgariType::Tsumo
+    } else {
+        AgariType::Ron
+    };
+
+    let hand = Replay::run(round, who, kyoku)?;
+    let (winning_code, mut hand_codes) = match agari_type {
+        AgariType::Tsumo => (*hand.hand.last().ok_or(BAD_LOG)?, hand.hand.clone()),
+        AgariType::Ron => (ron_tile(round, from_who)?, hand.hand.clone()),
+    };
+    let num_akadora = hand_codes
+        .iter()
+        .chain(hand.melds.iter().flat_map(|(_, c)| c))
+        .chain(hand.closed_kans.iter().flatten())
+        .chain((agari_type == AgariType::Ron).then_some(&winning_code))
+        .filter(|&&c| c >= 51)
+        .count() as u8;
+
+    let is_daburu_riichi = has("両立直");
+    let is_riichi = is_daburu_riichi || has("立直");
+    let player_context = PlayerContext {
+        jikaze: seat_wind(kyoku, who),
+        is_oya: who == index % 4,
+        is_riichi,
+        is_daburu_riichi,
+        is_ippatsu: has("一発"),
+        is_menzen: hand.melds.is_empty(),
+        discards: hand.discards.clone(),
+    };
+    let game_context = GameContext {
+        bakaze: WINDS[(index / 4 % 4) as usize],
+        kyoku,
+        honba,
+        riichi_sticks,
+        dora_indicators: tiles(round.get(2))?,
+        uradora_indicators: if is_riichi {
+            tiles(round.get(3))?
+        } else {
+            Vec::new()
+        },
+        num_akadora,
+        is_tenhou: has("天和"),
+        is_chiihou: has("地和"),
+        is_renhou: false,
+        is_haitei: has("海底摸月"),
+        is_houtei: has("河底撈魚"),
+        is_rinshan: has("嶺上開花"),
+        is_chankan: has("槍槓"),
+        pao_player: (pao_who != who).then(|| seat_wind(kyoku, pao_who)),
+        ..Default::default()
+    };
+
+    hand_codes.sort_unstable();
+    Ok(UserInput {
+        hand_tiles: hand_codes
+            .iter()
+            .map(|&c| tile(c))
+            .collect::<Result<_, _>>()?,
+        winning_tile: tile(winning_code)?,
+        open_melds: hand.melds.into_iter().map(|(meld, _)| meld).collect(),
+        closed_kans: hand
+            .closed_kans
+            .iter()
+            .map(|kan| tile(kan[0]))
+            .collect::<Result<_, _>>()?,
+        player_context,
+        game_context,
+        agari_type,
+    })
+}
+
+// ["和了", [point deltas], [who, fromWho, paoWho, "30符1飜1000点", yaku...], ...]
+fn agari_entry(round: &Value) -> Option<&Vec<Value>> {
+    let result = round.as_array()?.last()?.as_array()?;
+    (result.first()?.as_str()? == "和了")
+        .then(|| result.get(2)?.as_array())
+        .flatten()
+}
+
+// Tenhou's numbering: 11-19 man, 21-29 pin, 31-39 sou, 41-47 ESWN then
+// Haku Hatsu Chun, 51-53 the red 5m 5p 5s
+fn tile(code: u8) -> Result<Hai, &'static str> {
+    let (suit, n) = ((code / 10) as usize, (code % 10) as usize);
+    match (suit, n) {
+        (1..=3, 1..=9) => Ok(index_to_tile((suit - 1) * 9 + n - 1)),
+        (4, 1..=7) => Ok(index_to_tile(26 + n)),
+        (5, 1..=3) => Ok(index_to_tile((n - 1) * 9 + 4)),
+        _ => Err("Unknown Tenhou tile code"),
+    }
+}
+
+fn codes(value: Option<&Value>) -> Result<Vec<u8>, &'static str> {
+    value
+        .and_then(Value::as_array)
+        .ok_or(BAD_LOG)?
+        .iter()
+        .map(|v| v.as_u64().map(|n| n as u8).ok_or(BAD_LOG))
+        .collect()
+}
+
+fn tiles(value: Option<&Value>) -> Result<Vec<Hai>, &'static str> {
+    codes(value)?.into_iter().map(tile).collect()
+}
+
+fn player(value: &Value) -> Result<u8, &'static str> {
+    value
+        .as_u64()
+        .filter(|&p| p < 4)
+        .map(|p| p as u8)
+        .ok_or(BAD_LOG)
+}
+
+// the tile the discarder let go last: a discard, 60 for the drawn tile, or
+// the added tile of a robbed kan
+fn ron_tile(round: &[Value], discarder: u8) -> Result<u8, &'static str> {
+    let takes = round
+        .get(5 + 3 * discarder as usize)
+        .and_then(Value::as_array);
+    let last_draw = || {
+        takes
+            .and_then(|t| t.iter().rev().find_map(Value::as_u64))
+            .map(|n| n as u8)
+            .ok_or(BAD_LOG)
+    };
+    let discards = round
+        .get(6 + 3 * discarder as usize)
+        .and_then(Value::as_array)
+        .ok_or(BAD_LOG)?;
+    let code = match discards.last().ok_or(BAD_LOG)? {
+        Value::Number(n) => n.as_u64().ok_or(BAD_LOG)? as u8,
+        Value::String(s) => Call::parse(s)?.called,
+        _ => return Err(BAD_LOG),
+    };
+    if code == 60 { last_draw() } else { Ok(code) }
+}
+
+// a call or special discard: "c275226", "p414141", "m39393939", "3737k3737",
+// "424242a42", "r45". The letter comes right before the called or added tile
+// and its position tells which seat the tile came from
+struct Call {
+    kind: char,
+    tiles: Vec<u8>,
+    called: u8,
+    offset: u8, // seats after the caller: 3 kamicha, 2 toimen, 1 shimocha
+}
+
+impl Call {
+    fn parse(s: &str) -> Result<Self, &'static str> {
+        let pos = s.find(|c: char| c.is_ascii_alphabetic()).ok_or(BAD_LOG)?;
+        let digits: String = s.chars().filter(char::is_ascii_digit).collect();
+        if !digits.len().is_multiple_of(2) {
+            return Err(BAD_LOG);
+        }
+        let tiles = (0..digits.len())
+            .step_by(2)
+            .map(|i| digits[i..i + 2].parse().map_err(|_| BAD_LOG))
+            .collect::<Result<Vec<u8>, _>>()?;
+        Ok(Call {
+            kind: s[pos..].chars().next().ok_or(BAD_LOG)?,
+            called: s
+                .get(pos + 1..pos + 3)
+                .and_then(|c| c.parse().ok())
+                .ok_or(BAD_LOG)?,
+            tiles,
+            offset: match pos {
+                0 => 3,
+                2 => 2,
+                _ => 1,
+            },
+        })
+    }
+}
+
+// the winner's tiles as raw codes, so red fives survive until they are counted
+struct Replay {
+    hand: Vec<u8>,
+    melds: Vec<(OpenMeldInput, Vec<u8>)>,
+    closed_kans: Vec<Vec<u8>>,
+    discards: Vec<Hai>,
+}
+
+impl Replay {
+    fn run(round: &[Value], who: u8, kyoku: u8) -> Result<Self, &'static str> {
+        let seat = 4 + 3 * who as usize;
+        let mut replay = Replay {
+            hand: codes(round.get(seat))?,
+            melds: Vec::new(),
+            closed_kans: Vec::new(),
+            discards: Vec::new(),
+        };
+        let takes = round
+            .get(seat + 1)
+            .and_then(Value::as_array)
+            .ok_or(BAD_LOG)?;
+        let discards = round
+            .get(seat + 2)
+            .and_then(Value::as_array)
+            .ok_or(BAD_LOG)?;
+
+        for (i, take) in takes.iter().enumerate() {
+            let drawn = match take {
+                Value::Number(n) => {
+                    let code = n.as_u64().ok_or(BAD_LOG)? as u8;
+                    replay.hand.push(code);
+                    Some(code)
+                }
+                Value::String(s) => {
+                    replay.call(&Call::parse(s)?, who, kyoku)?;
+                    None
+                }
+                _ => return Err(BAD_LOG),
+            };
+            if let Some(discard) = discards.get(i) {
+                replay.discard(discard, drawn)?;
+            }
+        }
+        Ok(replay)
+    }
+
+    fn remove(&mut self, code: u8) -> Result<(), &'static str> {
+        let pos = self
+            .hand
+            .iter()
+            .position(|&c| c == code)
+            .ok_or("Tenhou log removes a tile the winner does not hold")?;
+        self.hand.remove(pos);
+        Ok(())
+    }
+
+    // chi, pon or daiminkan on another player's discard
+    fn call(&mut self, call: &Call, who: u8, kyoku: u8) -> Result<(), &'static str> {
+        let mut own = call.tiles.clone();
+        let called = own.iter().position(|&c| c == call.called).ok_or(BAD_LOG)?;
+        own.remove(called);
+        for &code in &own {
+            self.remove(code)?;
+        }
+
+        let hais = call
+            .tiles
+            .iter()
+            .map(|&c| tile(c))
+            .collect::<Result<Vec<_>, _>>()?;
+        let meld = match call.kind {
+            'c' => OpenMeldInput::chi(*hais.iter().min().ok_or(BAD_LOG)?),
+            'p' => OpenMeldInput::pon(hais[0]),
+            'm' => OpenMeldInput::kan(hais[0], KanType::Daiminkan),
+            _ => return Err(BAD_LOG),
+        };
+        let from = seat_wind(kyoku, (who + call.offset) % 4);
+        self.melds
+            .push((meld.called(tile(call.called)?, from), call.tiles.clone()));
+        Ok(())
+    }
+
+    // 0 follows a daiminkan, 60 throws the drawn tile
+    fn discard(&mut self, discard: &Value, drawn: Option<u8>) -> Result<(), &'static str> {
+        let code = match discard {
+            Value::Number(n) => n.as_u64().ok_or(BAD_LOG)? as u8,
+            Value::String(s) => {
+                let call = Call::parse(s)?;
+                match call.kind {
+                    'r' => call.called,
+                    'a' => {
+                        for &code in &call.tiles {
+                            self.remove(code)?;
+                        }
+                        self.closed_kans.push(call.tiles);
+                        return Ok(());
+                    }
+                    'k' => {
+                        self.remove(call.called)?;
+                        let added = tile(call.called)?;
+                        let (meld, codes) = self
+                            .melds
+                            .iter_mut()
+                            .find(|(m, _)| {
+                                m.mentsu_type == MentsuType::Koutsu
+                                    && m.representative_tile == added
+                            })
+                            .ok_or(BAD_LOG)?;
+                        meld.mentsu_type = MentsuType::Kantsu;
+                        meld.kan_type = Some(KanType::Shouminkan);
+                        codes.push(call.called);
+                        return Ok(());
+                    }
+                    _ => return Err(BAD_LOG),
+                }
+            }
+            _ => return Err(BAD_LOG),
+        };
+        let code = match code {
+            0 => return Ok(()),
+            60 => drawn.ok_or(BAD_LOG)?,
+            code => code,
+        };
+        self.remove(code)?;
+        self.discards.push(tile(code)?);
+        Ok(())
+    }
+}
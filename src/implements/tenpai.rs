@@ -0,0 +1,317 @@
+use crate::implements::calculate_agari;
+use crate::implements::raw_hand_organizer::organize_hand_split;
+use crate::implements::types::{
+    game::{AgariType, GameContext, PlayerContext},
+    hand::{HandOrganization, Mentsu},
+    input::UserInput,
+    scoring::AgariResult,
+    tiles::{Hai, tile_to_index},
+};
+use crate::implements::yaku_checkers::{utils::check_chiitoitsu, yakuman::check_kokushi};
+use std::collections::HashSet;
+
+// every tile that completes the concealed tiles and melds into a winning shape
+pub fn waits(concealed: &[Hai], melds: &[Mentsu]) -> Vec<Hai> {
+    let mut held = [0u8; 34];
+    for tile in concealed
+        .iter()
+        .chain(melds.iter().flat_map(Mentsu::tiles_slice))
+    {
+        held[tile_to_index(tile)] += 1;
+    }
+
+    Hai::all()
+        // a tile already held 4 times can't be drawn
+        .filter(|tile| held[tile_to_index(tile)] < 4)
+        .filter(|tile| {
+            organize_hand_split(concealed, *tile, melds)
+                .is_ok_and(|organizations| organizations.iter().any(is_complete))
+        })
+        .collect()
+}
+
+// 受け入れ: each wait of a closed hand with the copies still unseen, 4 less
+// those held and those visible (discards, melds, dora indicators)
+pub fn ukeire(tiles: &[Hai], visible: &[Hai]) -> Vec<(Hai, u8)> {
+    let mut seen = [0u8; 34];
+    for tile in tiles.iter().chain(visible) {
+        seen[tile_to_index(tile)] += 1;
+    }
+    waits(tiles, &[])
+        .into_iter()
+        .map(|tile| (tile, 4u8.saturating_sub(seen[tile_to_index(&tile)])))
+        .collect()
+}
+
+// the best result for each winning tile, waits without a yaku are left out
+pub fn best_agari(
+    concealed: &[Hai],
+    melds: &[Mentsu],
+    player: &PlayerContext,
+    game: &GameContext,
+    agari_type: AgariType,
+) -> Vec<(Hai, AgariResult)> {
+    waits(concealed, melds)
+        .into_iter()
+        .filter_map(|tile| {
+            let input = UserInput::from_mentsu(concealed, tile, melds, player, game, agari_type);
+            calculate_agari(&input).ok().map(|result| (tile, result))
+        })
+        .collect()
+}
+
+// tiles from a winning shape, -1 once complete; the hand may be short by
+// called melds (1, 4, 7, 10, 13 or 14 tiles and so on), None for other sizes
+pub fn shanten(tiles: &[Hai]) -> Option<i8> {
+    if tiles.len() > 14 || tiles.len().is_multiple_of(3) {
+        return None;
+    }
+    let mut counts = [0u8; 34];
+    for tile in tiles {
+        counts[tile_to_index(tile)] += 1;
+    }
+
+    let max_groups = (tiles.len() / 3) as i8;
+    let mut best = regular_shanten(&counts, max_groups);
+
+    // Chiitoitsu and Kokushi need a closed 13/14 tile hand
+    if tiles.len() >= 13 {
+        let pairs = counts.iter().filter(|&&n| n >= 2).count() as i8;
+        let kinds = counts.iter().filter(|&&n| n > 0).count() as i8;
+        best = best.min(6 - pairs + (7 - kinds).max(0));
+
+        let yaochuu = Hai::all()
+            .filter(Hai::is_yaochuu)
+            .map(|t| counts[tile_to_index(&t)]);
+        let (kinds, has_pair) =
+            yaochuu.fold((0, false), |(k, p), n| (k + (n > 0) as i8, p || n >= 2));
+        best = best.min(13 - kinds - has_pair as i8);
+    }
+    Some(best)
+}
+
+// tiles that would lower the shanten of a hand waiting for its next draw
+pub fn advancing_tiles(tiles: &[Hai]) -> Vec<Hai> {
+    let Some(current) = shanten(tiles).filter(|_| tiles.len() % 3 == 1) else {
+        return Vec::new();
+    };
+    let mut hand = tiles.to_vec();
+    Hai::all()
+        .filter(|tile| tiles.iter().filter(|t| *t == tile).count() < 4)
+        .filter(|tile| {
+            hand.push(*tile);
+            let lower = shanten(&hand).is_some_and(|s| s < current);
+            hand.pop();
+            lower
+        })
+        .collect()
+}
+
+// suits don't interact, so each suit's (melds, partial melds, pair) readings
+// are enumerated on their own and combined
+fn regular_shanten(counts: &[u8; 34], max_groups: i8) -> i8 {
+    let mut combined = vec![(0i8, 0i8, false)];
+    for suit in 0..4 {
+        let start = suit * 9;
+        let mut suit_counts = counts[start..(start + 9).min(34)].to_vec();
+        let mut readings = Vec::new();
+        let mut seen = HashSet::new();
+        let is_honor = suit == 3;
+        suit_readings(
+            &mut suit_counts,
+            (0, 0, false),
+            is_honor,
+            &mut seen,
+            &mut readings,
+        );
+        readings.sort();
+        readings.dedup();
+
+        let mut next = Vec::new();
+        for &(m1, t1, p1) in &combined {
+            for &(m2, t2, p2) in readings.iter().filter(|r| !(p1 && r.2)) {
+                next.push((
+                    (m1 + m2).min(max_groups),
+                    (t1 + t2).min(max_groups),
+                    p1 || p2,
+                ));
+            }
+        }
+        next.sort();
+        next.dedup();
+        combined = next;
+    }
+
+    combined
+        .iter()
+        .map(|&(melds, partials, pair)| {
+            let partials = partials.min(max_groups - melds);
+            2 * max_groups - 2 * melds - partials - pair as i8
+        })
+        .min()
+        .unwrap_or(2 * max_groups)
+}
+
+// depth-first over one suit's counts, honors form no sequences; seen skips
+// (remaining counts, state) pairs already explored by another order of takes
+fn suit_readings(
+    counts: &mut [u8],
+    state: (i8, i8, bool),
+    is_honor: bool,
+    seen: &mut HashSet<(u32, (i8, i8, bool))>,
+    out: &mut Vec<(i8, i8, bool)>,
+) {
+    let key = counts.iter().fold(0u32, |key, &n| key * 5 + n as u32);
+    if !seen.insert((key, state)) {
+        return;
+    }
+    let (melds, partials, pair) = state;
+    let Some(i) = counts.iter().position(|&n| n > 0) else {
+        out.push(state);
+        return;
+    };
+    let count = counts[i];
+    let next = !is_honor && i < 8 && counts[i + 1] > 0;
+    let skip = !is_honor && i < 7 && counts[i + 2] > 0;
+    let mut branch = |taken: &[usize], state| {
+        for &j in taken {
+            counts[j] -= 1;
+        }
+        suit_readings(counts, state, is_honor, seen, out);
+        for &j in taken {
+            counts[j] += 1;
+        }
+    };
+
+    if count >= 3 {
+        branch(&[i, i, i], (melds + 1, partials, pair));
+    }
+    if next && skip {
+        branch(&[i, i + 1, i + 2], (melds + 1, partials, pair));
+    }
+    if count >= 2 {
+        if !pair {
+            branch(&[i, i], (melds, partials, true));
+        }
+        branch(&[i, i], (melds, partials + 1, pair));
+    }
+    if next {
+        branch(&[i, i + 1], (melds, partials + 1, pair));
+    }
+    if skip {
+        branch(&[i, i + 2], (melds, partials + 1, pair));
+    }
+    // leave the tile isolated
+    branch(&[i], (melds, partials, pair));
+}
+
+// 振聴: a ron is not allowed while any wait sits in the player's own discards
+pub fn is_furiten(waits: &[Hai], discards: &[Hai]) -> bool {
+    waits.iter().any(|tile| discards.contains(tile))
+}
+
+// Chiitoitsu under the standard rule, seven distinct pairs
+fn is_complete(organization: &HandOrganization) -> bool {
+    match organization {
+        HandOrganization::YonmentsuIchiatama(_) => true,
+        HandOrganization::Irregular { counts, agari_hai } => {
+            check_kokushi(counts, *agari_hai).is_some()
+                || check_chiitoitsu(counts, *agari_hai, false).is_some()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implements::notation::parse_hand;
+    use crate::implements::tiles::Kaze;
+
+    fn shanten_of(notation: &str) -> Option<i8> {
+        shanten(&parse_hand(notation).unwrap())
+    }
+
+    #[test]
+    fn shanten_of_closed_hands() {
+        assert_eq!(shanten_of("123m456p789s11z999m"), Some(-1));
+        assert_eq!(shanten_of("123m456p789s11z99m"), Some(0));
+        assert_eq!(shanten_of("123m456p78s11z359m"), Some(1));
+        // one pair short of Chiitoitsu, thirteen kinds of Kokushi
+        assert_eq!(shanten_of("1199m2255p3388s1z"), Some(0));
+        assert_eq!(shanten_of("19m19p19s1234567z"), Some(0));
+    }
+
+    #[test]
+    fn shanten_of_hands_short_by_calls() {
+        assert_eq!(shanten_of("123m5z"), Some(0));
+        assert_eq!(shanten_of("15m9p5z"), Some(2));
+        assert_eq!(shanten_of("123m456p5z"), Some(0));
+        assert_eq!(shanten_of("13m46p89s5z"), Some(2));
+    }
+
+    #[test]
+    fn shanten_rejects_multiples_of_three() {
+        for notation in ["123m", "123m456p", "123m456p789s"] {
+            assert_eq!(shanten_of(notation), None, "{notation}");
+        }
+        assert_eq!(shanten(&[]), None);
+    }
+
+    #[test]
+    fn advancing_tiles_of_a_tenpai_hand_are_its_waits() {
+        let hand = parse_hand("123m456p789s11z99m").unwrap();
+        assert_eq!(advancing_tiles(&hand), parse_hand("9m1z").unwrap());
+        assert_eq!(
+            advancing_tiles(&parse_hand("123m456p789s11z999m").unwrap()),
+            vec![]
+        );
+    }
+
+    fn south() -> PlayerContext {
+        PlayerContext {
+            jikaze: Kaze::Nan,
+            is_oya: false,
+            ..Default::default()
+        }
+    }
+
+    fn summaries(notation: &str, agari_type: AgariType) -> Vec<(Hai, (u8, u8, u32))> {
+        let hand = parse_hand(notation).unwrap();
+        best_agari(&hand, &[], &south(), &GameContext::default(), agari_type)
+            .into_iter()
+            .map(|(tile, result)| (tile, result.summary()))
+            .collect()
+    }
+
+    #[test]
+    fn two_sided_wait_scores_each_tile() {
+        let hand = parse_hand("234m234p23s567s99s").unwrap();
+        assert_eq!(waits(&hand, &[]), parse_hand("14s").unwrap());
+        // 4s adds Sanshoku, 1s is Pinfu alone
+        let tiles = parse_hand("14s").unwrap();
+        assert_eq!(
+            summaries("234m234p23s567s99s", AgariType::Ron),
+            vec![(tiles[0], (1, 30, 1000)), (tiles[1], (3, 30, 3900))]
+        );
+    }
+
+    #[test]
+    fn wait_without_a_yaku_is_dropped() {
+        let hand = parse_hand("123m456p789s33s55z").unwrap();
+        assert_eq!(waits(&hand, &[]), parse_hand("3s5z").unwrap());
+        let haku = parse_hand("5z").unwrap()[0];
+        assert_eq!(
+            summaries("123m456p789s33s55z", AgariType::Ron),
+            vec![(haku, (1, 40, 1300))]
+        );
+    }
+
+    #[test]
+    fn ron_and_tsumo_score_the_same_wait_differently() {
+        let one_sou = parse_hand("1s").unwrap()[0];
+        let ron = summaries("234m234p23s567s99s", AgariType::Ron);
+        let tsumo = summaries("234m234p23s567s99s", AgariType::Tsumo);
+        assert_eq!(ron[0], (one_sou, (1, 30, 1000)));
+        assert_eq!(tsumo[0], (one_sou, (2, 20, 1500)));
+    }
+}
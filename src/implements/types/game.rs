@@ -1,43 +1,351 @@
+use super::rules::RuleConfig;
 use super::tiles::{Hai, Kaze};
+use crate::implements::input_validator::validate_game_context;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 // win type
 pub enum AgariType {
     Tsumo, // 自摸 (Self-draw)
-    Ron,   // 栄和 (Win off discard)
+    #[default]
+    Ron, // 栄和 (Win off discard)
 }
 
-impl Default for AgariType {
-    fn default() -> Self {
-        AgariType::Ron
-    }
+// yonma seat wind of player_index (0-3, player 0 deals East 1), the deal passes on each kyoku
+pub fn seat_wind(kyoku: u8, player_index: u8) -> Kaze {
+    const WINDS: [Kaze; 4] = [Kaze::Ton, Kaze::Nan, Kaze::Shaa, Kaze::Pei];
+    let dealer = (kyoku + 3) % 4;
+    WINDS[((player_index + 4 - dealer) % 4) as usize]
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 // Context winning hand
 pub struct PlayerContext {
     pub jikaze: Kaze,           // 自風 (Seat Wind)
     pub is_oya: bool,           // 親 (dealer)
     pub is_riichi: bool,        // 立直 (Riichi)
     pub is_daburu_riichi: bool, // ダブル立直 (Double Riichi)
-    pub is_ippatsu: bool,       // 一発 (Ippatsu)
+    pub is_ippatsu: bool,       // 一発 (Ippatsu), caller clears it after any call
     pub is_menzen: bool,        // 門前 (fully concealed)
+    pub discards: Vec<Hai>,     // 捨て牌 (own discards), only read for the furiten flag
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 // Context current round
 pub struct GameContext {
     pub bakaze: Kaze,                 // 場風 (Prevalent Wind)
+    pub kyoku: u8,                    // 局 (hand number within the round, from 1)
     pub honba: u8,                    // 本場 (Honba counter)
+    pub riichi_sticks: u8,            // 供託 (Riichi deposits on the table)
     pub dora_indicators: Vec<Hai>,    // ドラ表示牌 (Dora indicators)
     pub uradora_indicators: Vec<Hai>, // 裏ドラ表示牌 (Ura Dora indicators)
     pub num_akadora: u8,              // 赤ドラ (Red Dora)
     // Special yaku flags
-    pub is_tenhou: bool,  // 天和 (Blessing of Heaven)
-    pub is_chiihou: bool, // 地和 (Blessing of Earth)
-    pub is_renhou: bool,  // 人和 (Blessing of Man)
-    pub is_haitei: bool,  // 海底 (last draw)
-    pub is_houtei: bool,  // 河底 (last discard)
-    pub is_rinshan: bool, // 嶺上 (After a Kan)
-    pub is_chankan: bool, // 搶槓 (Robbing a Kan)
+    pub is_tenhou: bool,          // 天和 (Blessing of Heaven)
+    pub is_chiihou: bool,         // 地和 (Blessing of Earth)
+    pub is_renhou: bool,          // 人和 (Blessing of Man)
+    pub is_haitei: bool,          // 海底 (last draw)
+    pub is_houtei: bool,          // 河底 (last discard)
+    pub is_rinshan: bool,         // 嶺上 (After a Kan)
+    pub is_chankan: bool,         // 搶槓 (Robbing a Kan)
+    pub pao_player: Option<Kaze>, // 責任払い (fed the last Daisangen/Daisuushi meld)
+    pub rules: RuleConfig,
+}
+
+// East seat (the dealer) with a closed hand and no riichi
+impl Default for PlayerContext {
+    fn default() -> Self {
+        PlayerContext {
+            jikaze: Kaze::Ton,
+            is_oya: true,
+            is_riichi: false,
+            is_daburu_riichi: false,
+            is_ippatsu: false,
+            is_menzen: true,
+            discards: Vec::new(),
+        }
+    }
+}
+
+impl PlayerContext {
+    pub fn builder() -> PlayerContextBuilder {
+        PlayerContextBuilder::default()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+// fluent PlayerContext construction, starts from PlayerContext::default()
+pub struct PlayerContextBuilder {
+    context: PlayerContext,
+}
+
+impl PlayerContextBuilder {
+    // seat wind, East is the dealer
+    pub fn seat(mut self, jikaze: Kaze) -> Self {
+        self.context.jikaze = jikaze;
+        self.context.is_oya = jikaze == Kaze::Ton;
+        self
+    }
+
+    pub fn riichi(mut self) -> Self {
+        self.context.is_riichi = true;
+        self
+    }
+
+    pub fn daburu_riichi(mut self) -> Self {
+        self.context.is_riichi = true;
+        self.context.is_daburu_riichi = true;
+        self
+    }
+
+    pub fn ippatsu(mut self) -> Self {
+        self.context.is_ippatsu = true;
+        self
+    }
+
+    // hand has called melds
+    pub fn open(mut self) -> Self {
+        self.context.is_menzen = false;
+        self
+    }
+
+    pub fn discards(mut self, discards: Vec<Hai>) -> Self {
+        self.context.discards = discards;
+        self
+    }
+
+    pub fn build(self) -> Result<PlayerContext, &'static str> {
+        let c = self.context;
+        if (c.is_riichi || c.is_daburu_riichi) && !c.is_menzen {
+            return Err("Riichi requires a closed hand");
+        }
+        if c.is_ippatsu && !(c.is_riichi || c.is_daburu_riichi) {
+            return Err("Ippatsu requires Riichi");
+        }
+        Ok(c)
+    }
+}
+
+// East 1, no honba, sticks or dora, no situational flags, default rules
+impl Default for GameContext {
+    fn default() -> Self {
+        GameContext {
+            bakaze: Kaze::Ton,
+            kyoku: 1,
+            honba: 0,
+            riichi_sticks: 0,
+            dora_indicators: Vec::new(),
+            uradora_indicators: Vec::new(),
+            num_akadora: 0,
+            is_tenhou: false,
+            is_chiihou: false,
+            is_renhou: false,
+            is_haitei: false,
+            is_houtei: false,
+            is_rinshan: false,
+            is_chankan: false,
+            pao_player: None,
+            rules: RuleConfig::default(),
+        }
+    }
+}
+
+impl GameContext {
+    pub fn builder() -> GameContextBuilder {
+        GameContextBuilder::default()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+// fluent GameContext construction, starts from GameContext::default()
+pub struct GameContextBuilder {
+    context: GameContext,
+}
+
+impl GameContextBuilder {
+    pub fn round(mut self, bakaze: Kaze) -> Self {
+        self.context.bakaze = bakaze;
+        self
+    }
+
+    pub fn kyoku(mut self, kyoku: u8) -> Self {
+        self.context.kyoku = kyoku;
+        self
+    }
+
+    pub fn honba(mut self, honba: u8) -> Self {
+        self.context.honba = honba;
+        self
+    }
+
+    pub fn riichi_sticks(mut self, riichi_sticks: u8) -> Self {
+        self.context.riichi_sticks = riichi_sticks;
+        self
+    }
+
+    // one call per indicator
+    pub fn dora(mut self, indicator: Hai) -> Self {
+        self.context.dora_indicators.push(indicator);
+        self
+    }
+
+    pub fn uradora(mut self, indicator: Hai) -> Self {
+        self.context.uradora_indicators.push(indicator);
+        self
+    }
+
+    pub fn akadora(mut self, count: u8) -> Self {
+        self.context.num_akadora = count;
+        self
+    }
+
+    pub fn tenhou(mut self) -> Self {
+        self.context.is_tenhou = true;
+        self
+    }
+
+    pub fn chiihou(mut self) -> Self {
+        self.context.is_chiihou = true;
+        self
+    }
+
+    pub fn renhou(mut self) -> Self {
+        self.context.is_renhou = true;
+        self
+    }
+
+    pub fn haitei(mut self) -> Self {
+        self.context.is_haitei = true;
+        self
+    }
+
+    pub fn houtei(mut self) -> Self {
+        self.context.is_houtei = true;
+        self
+    }
+
+    pub fn rinshan(mut self) -> Self {
+        self.context.is_rinshan = true;
+        self
+    }
+
+    pub fn chankan(mut self) -> Self {
+        self.context.is_chankan = true;
+        self
+    }
+
+    pub fn pao(mut self, liable: Kaze) -> Self {
+        self.context.pao_player = Some(liable);
+        self
+    }
+
+    pub fn rules(mut self, rules: RuleConfig) -> Self {
+        self.context.rules = rules;
+        self
+    }
+
+    // the same context checks validate_input runs
+    pub fn build(self) -> Result<GameContext, &'static str> {
+        validate_game_context(&self.context)?;
+        Ok(self.context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implements::calculate_agari;
+    use crate::implements::input::UserInput;
+    use crate::implements::notation::parse_hand;
+    use crate::implements::yaku::Yaku;
+
+    #[test]
+    fn default_contexts_score_a_valid_hand() {
+        let player = PlayerContext::default();
+        assert_eq!(
+            (player.jikaze, player.is_oya, player.is_menzen),
+            (Kaze::Ton, true, true)
+        );
+        let game = GameContext::default();
+        assert_eq!((game.bakaze, game.kyoku, game.honba), (Kaze::Ton, 1, 0));
+        assert!(game.dora_indicators.is_empty());
+
+        let input = UserInput {
+            hand_tiles: parse_hand("123m456p789s999m11z").unwrap(),
+            winning_tile: Hai::try_from("3m").unwrap(),
+            open_melds: Vec::new(),
+            closed_kans: Vec::new(),
+            player_context: player,
+            game_context: game,
+            agari_type: AgariType::Tsumo,
+        };
+        let result = calculate_agari(&input).unwrap();
+        assert_eq!(result.yaku_list, vec![Yaku::MenzenTsumo]);
+        assert_eq!(result.summary(), (1, 40, 2100));
+    }
+
+    #[test]
+    fn builders_chain_setters() {
+        let player = PlayerContext::builder()
+            .seat(Kaze::Nan)
+            .riichi()
+            .ippatsu()
+            .build()
+            .unwrap();
+        assert_eq!(player.jikaze, Kaze::Nan);
+        assert!(!player.is_oya && player.is_riichi && player.is_ippatsu && player.is_menzen);
+
+        let game = GameContext::builder().build().unwrap();
+        assert_eq!(game.kyoku, GameContext::default().kyoku);
+    }
+
+    #[test]
+    fn player_builder_rejects_contradictions() {
+        assert_eq!(
+            PlayerContext::builder()
+                .riichi()
+                .open()
+                .build()
+                .unwrap_err(),
+            "Riichi requires a closed hand"
+        );
+        assert_eq!(
+            PlayerContext::builder().ippatsu().build().unwrap_err(),
+            "Ippatsu requires Riichi"
+        );
+    }
+
+    #[test]
+    fn seat_winds_rotate_with_the_deal() {
+        use Kaze::{Nan, Pei, Shaa, Ton};
+        let expected = [
+            [Ton, Nan, Shaa, Pei],
+            [Pei, Ton, Nan, Shaa],
+            [Shaa, Pei, Ton, Nan],
+            [Nan, Shaa, Pei, Ton],
+        ];
+        // East 1-4 and South 1-4 seat the players the same way
+        for kyoku in 1..=4u8 {
+            let winds: Vec<Kaze> = (0..4).map(|p| seat_wind(kyoku, p)).collect();
+            assert_eq!(winds, expected[kyoku as usize - 1], "kyoku {kyoku}");
+        }
+    }
+
+    #[test]
+    fn game_builder_rejects_what_the_validator_rejects() {
+        assert_eq!(
+            GameContext::builder().kyoku(5).build().unwrap_err(),
+            "Kyoku must be between 1 and 4 (3 in sanma)"
+        );
+        assert_eq!(
+            GameContext::builder()
+                .chankan()
+                .houtei()
+                .build()
+                .unwrap_err(),
+            "Chankan cannot be combined with Houtei"
+        );
+    }
 }
@@ -1,6 +1,7 @@
-use super::tiles::Hai;
+use super::tiles::{Hai, tile_to_index};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MentsuType {
     Shuntsu, // 順子 (Sequence)
     Koutsu,  // 刻子 (Triplet)
@@ -8,6 +9,15 @@ pub enum MentsuType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KanType {
+    // how an open kan was called
+    Daiminkan,  // 大明槓 (kan on a discard)
+    Shouminkan, // 小明槓 (pon upgraded with a drawn tile)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Mentsu {
     // 面子 (Meld)
     pub mentsu_type: MentsuType,
@@ -15,7 +25,18 @@ pub struct Mentsu {
     pub tiles: [Hai; 4],
 }
 
+impl Mentsu {
+    // the meld's real tiles, the 4th slot of a shuntsu or koutsu is only padding
+    pub fn tiles_slice(&self) -> &[Hai] {
+        match self.mentsu_type {
+            MentsuType::Kantsu => &self.tiles,
+            MentsuType::Shuntsu | MentsuType::Koutsu => &self.tiles[..3],
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Machi {
     // 待ち (Wait)
     Ryanmen, // 両面 (Two-Sided)
@@ -29,7 +50,8 @@ pub enum Machi {
     KokushiJusanmen, // 国士十三面 (Kokushi 13-sided wait)
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AgariHand {
     // 和了手 (Winning Hand)
     pub mentsu: [Mentsu; 4], // 面子 (4 melds)
@@ -38,7 +60,25 @@ pub struct AgariHand {
     pub machi: Machi,        // 待ち (The wait type)
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HandMeta {
+    // declared-meld facts, taken once while organizing the hand
+    pub kan_count: usize, // open and closed kans, each adding a dora indicator
+}
+
+impl HandMeta {
+    pub fn from_melds(melds: &[Mentsu]) -> Self {
+        HandMeta {
+            kan_count: melds
+                .iter()
+                .filter(|m| m.mentsu_type == MentsuType::Kantsu)
+                .count(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum HandOrganization {
     YonmentsuIchiatama(AgariHand), // 四面子一頭 (4 Melds, 1 Pair)
     Irregular {
@@ -48,7 +88,19 @@ pub enum HandOrganization {
     },
 }
 
-#[derive(Debug, Clone)]
+impl HandOrganization {
+    // counts are rebuilt from the concealed tiles (winning tile included)
+    pub fn irregular_from_tiles(tiles: &[Hai], agari_hai: Hai) -> Self {
+        let mut counts = [0u8; 34];
+        for tile in tiles {
+            counts[tile_to_index(tile)] += 1;
+        }
+        HandOrganization::Irregular { counts, agari_hai }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HandStructure {
     YonmentsuIchiatama(AgariHand),
 
@@ -73,3 +125,27 @@ pub enum HandStructure {
         _is_junsei: bool, // 純正 (true 9-sided wait)
     },
 }
+
+impl HandStructure {
+    // every tile of the winning hand, winning tile included: 14 plus one per kan
+    pub fn tiles(&self) -> Vec<Hai> {
+        match self {
+            HandStructure::YonmentsuIchiatama(hand) | HandStructure::ChuurenPoutou { hand, .. } => {
+                let mut tiles = vec![hand.atama.0, hand.atama.1];
+                for mentsu in &hand.mentsu {
+                    tiles.extend_from_slice(mentsu.tiles_slice());
+                }
+                tiles
+            }
+            HandStructure::Chiitoitsu { pairs, .. } => {
+                pairs.iter().flat_map(|&(t1, t2)| [t1, t2]).collect()
+            }
+            // the 13 kinds plus the pair's second tile
+            HandStructure::KokushiMusou { tiles, atama, .. } => {
+                let mut all = tiles.to_vec();
+                all.push(atama.0);
+                all
+            }
+        }
+    }
+}
@@ -1,17 +1,58 @@
 use super::game::{AgariType, GameContext, PlayerContext};
-use super::hand::MentsuType;
-use super::tiles::Hai;
+use super::hand::{KanType, Mentsu, MentsuType};
+use super::tiles::{Hai, Kaze, tile_to_index};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OpenMeldInput {
     // type of meld
     pub mentsu_type: MentsuType,
 
     // single tile that uniquely represents the meld
     pub representative_tile: Hai,
+
+    // open kans only, both score the same
+    pub kan_type: Option<KanType>,
+
+    // the discard that was called and whose discard it was, when known
+    pub called_tile: Option<Hai>,
+    pub called_from: Option<Kaze>,
+}
+
+impl OpenMeldInput {
+    pub fn pon(tile: Hai) -> Self {
+        Self::new(MentsuType::Koutsu, tile, None)
+    }
+
+    // lowest tile of the run
+    pub fn chi(lowest: Hai) -> Self {
+        Self::new(MentsuType::Shuntsu, lowest, None)
+    }
+
+    pub fn kan(tile: Hai, kan_type: KanType) -> Self {
+        Self::new(MentsuType::Kantsu, tile, Some(kan_type))
+    }
+
+    // checked by validate_input: the tile belongs to the meld, chi only from the left
+    pub fn called(mut self, tile: Hai, from: Kaze) -> Self {
+        self.called_tile = Some(tile);
+        self.called_from = Some(from);
+        self
+    }
+
+    fn new(mentsu_type: MentsuType, representative_tile: Hai, kan_type: Option<KanType>) -> Self {
+        OpenMeldInput {
+            mentsu_type,
+            representative_tile,
+            kan_type,
+            called_tile: None,
+            called_from: None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UserInput {
     // Hand Composition
     pub hand_tiles: Vec<Hai>,
@@ -28,3 +69,53 @@ pub struct UserInput {
     pub game_context: GameContext,
     pub agari_type: AgariType,
 }
+
+impl UserInput {
+    // from already-built melds, concealed excludes the winning tile
+    pub fn from_mentsu(
+        concealed: &[Hai],
+        winning_tile: Hai,
+        melds: &[Mentsu],
+        player: &PlayerContext,
+        game: &GameContext,
+        agari_type: AgariType,
+    ) -> Self {
+        let mut hand_tiles = concealed.to_vec();
+        if agari_type == AgariType::Tsumo {
+            hand_tiles.push(winning_tile);
+        }
+
+        let (open, closed): (Vec<&Mentsu>, Vec<&Mentsu>) = melds.iter().partition(|m| m.is_minchou);
+        let open_melds = open
+            .iter()
+            .map(|m| match m.mentsu_type {
+                MentsuType::Koutsu => OpenMeldInput::pon(m.tiles[0]),
+                MentsuType::Shuntsu => OpenMeldInput::chi(m.tiles[0]),
+                MentsuType::Kantsu => OpenMeldInput::kan(m.tiles[0], KanType::Daiminkan),
+            })
+            .collect();
+
+        UserInput {
+            hand_tiles,
+            winning_tile,
+            open_melds,
+            closed_kans: closed.iter().map(|m| m.tiles[0]).collect(),
+            player_context: player.clone(),
+            game_context: game.clone(),
+            agari_type,
+        }
+    }
+
+    // concealed tiles by tile_to_index, winning tile included; what a UI
+    // shows for a NOT_A_WINNING_HAND error, which carries no data itself
+    pub fn tile_counts(&self) -> [u8; 34] {
+        let mut counts = [0u8; 34];
+        for tile in &self.hand_tiles {
+            counts[tile_to_index(tile)] += 1;
+        }
+        if self.agari_type == AgariType::Ron {
+            counts[tile_to_index(&self.winning_tile)] += 1;
+        }
+        counts
+    }
+}
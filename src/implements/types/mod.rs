@@ -1,6 +1,7 @@
 pub mod game;
 pub mod hand;
 pub mod input;
+pub mod rules;
 pub mod scoring;
 pub mod tiles;
 pub mod yaku;
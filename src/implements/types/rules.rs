@@ -0,0 +1,50 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlayMode {
+    #[default]
+    Yonma, // 四麻 (4 players)
+    Sanma, // 三麻 (3 players, no North seat, no 2-8m)
+}
+
+impl PlayMode {
+    // players other than the winner paying on tsumo
+    pub fn num_tsumo_payers(&self) -> u32 {
+        match self {
+            PlayMode::Yonma => 3,
+            PlayMode::Sanma => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+// House rules
+pub struct RuleConfig {
+    pub lenient_dora_count: bool, // log a warning instead of rejecting odd dora indicator counts
+    pub rinshan_haitei_allowed: bool, // rinshan draw may also be the last tile
+    pub play_mode: PlayMode,
+    pub kuitan: bool,        // 喰いタン (open Tanyao)
+    pub local_yakuman: bool, // Daisharin and its souzu/manzu analogues
+    // four of a kind may stand for two Chiitoitsu pairs; also the only way to a
+    // Ryuuiisou Chiitoitsu, as there are just six green tile kinds
+    pub chiitoitsu_allow_four: bool,
+    pub kiriage_mangan: bool, // 切り上げ満貫 (4 han 30 fu and 3 han 60 fu pay Mangan)
+    pub pinfu_guest_wind_ok: bool, // 客風 pair (neither round nor seat wind) allows Pinfu
+    pub max_yakuman: Option<u32>, // cap on the summed yakuman multiple, None for no cap
+}
+
+impl Default for RuleConfig {
+    fn default() -> Self {
+        RuleConfig {
+            lenient_dora_count: false,
+            rinshan_haitei_allowed: false,
+            play_mode: PlayMode::default(),
+            kuitan: true,
+            local_yakuman: false,
+            chiitoitsu_allow_four: false,
+            kiriage_mangan: false,
+            pinfu_guest_wind_ok: true,
+            max_yakuman: None,
+        }
+    }
+}
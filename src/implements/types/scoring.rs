@@ -1,17 +1,66 @@
 use super::game::AgariType;
+use super::hand::HandStructure;
 use super::yaku::Yaku;
+use std::fmt;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 // point limits
 pub enum HandLimit {
-    Mangan,    // 満貫
-    Haneman,   // 跳満
-    Baiman,    // 倍満
-    Sanbaiman, // 三倍満
-    Yakuman,   // 役満 (13han+)
+    Mangan,       // 満貫
+    Haneman,      // 跳満
+    Baiman,       // 倍満
+    Sanbaiman,    // 三倍満
+    KazoeYakuman, // 数え役満 (13han+ of regular yaku)
+    Yakuman,      // 役満
 }
 
-#[derive(Debug, Clone)]
+impl HandLimit {
+    pub fn name(&self) -> &'static str {
+        match self {
+            HandLimit::Mangan => "Mangan",
+            HandLimit::Haneman => "Haneman",
+            HandLimit::Baiman => "Baiman",
+            HandLimit::Sanbaiman => "Sanbaiman",
+            HandLimit::KazoeYakuman => "Kazoe Yakuman",
+            HandLimit::Yakuman => "Yakuman",
+        }
+    }
+
+    // basic points before the dealer/non-dealer multiplier
+    pub fn base_points(&self) -> u32 {
+        match self {
+            HandLimit::Mangan => 2000,
+            HandLimit::Haneman => 3000,
+            HandLimit::Baiman => 4000,
+            HandLimit::Sanbaiman => 6000,
+            HandLimit::KazoeYakuman | HandLimit::Yakuman => 8000,
+        }
+    }
+
+    // Mangan: 12000
+    pub fn dealer_ron(&self) -> u32 {
+        self.base_points() * 6
+    }
+
+    // Mangan: 8000
+    pub fn nondealer_ron(&self) -> u32 {
+        self.base_points() * 4
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+// dora han by source, kan_dora comes from every indicator after the first
+pub struct DoraBreakdown {
+    pub dora: u8,
+    pub ura: u8,
+    pub aka: u8,
+    pub kan_dora: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 // result for a winning hand
 pub struct AgariResult {
     pub han: u8,              // 飜 (Han count)
@@ -23,6 +72,201 @@ pub struct AgariResult {
     pub ko_payment: u32,
     pub total_payment: u32,
     pub honba: u8,
+    pub riichi_sticks: u8, // collected on top of total_payment, 1000 each
     pub agari_type: AgariType,
     pub is_oya: bool,
+    pub hand_structure: HandStructure, // decomposition the score was based on
+    pub pao_payment: u32,              // 責任払い share paid by GameContext::pao_player
+    pub dora_breakdown: DoraBreakdown,
+    pub is_furiten: bool, // ron with a wait in PlayerContext::discards, informational only
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+// everything in an AgariResult except the payments
+pub struct ScoredHand {
+    pub han: u8,
+    pub fu: u8,
+    pub yaku_list: Vec<Yaku>,
+    pub num_akadora: u8,
+    pub limit_name: Option<HandLimit>,
+    pub honba: u8,
+    pub riichi_sticks: u8,
+    pub is_oya: bool,
+    pub hand_structure: HandStructure,
+    pub dora_breakdown: DoraBreakdown,
+}
+
+// Payment fields by agari_type and is_oya, honba always included:
+// - Ron: total_payment comes from the discarder, oya_payment and ko_payment stay 0
+// - dealer Tsumo: oya_payment is paid by each non-dealer, ko_payment stays 0
+// - non-dealer Tsumo: oya_payment from the dealer, ko_payment from each other non-dealer
+// pao_payment is the part of total_payment owed by the liable player
+impl AgariResult {
+    pub fn ron(hand: ScoredHand, total_payment: u32, pao_payment: u32) -> Self {
+        Self::from_parts(hand, AgariType::Ron, (0, 0, total_payment), pao_payment)
+    }
+
+    // a dealer's ko_payment is ignored
+    pub fn tsumo(
+        hand: ScoredHand,
+        oya_payment: u32,
+        ko_payment: u32,
+        total_payment: u32,
+        pao_payment: u32,
+    ) -> Self {
+        let ko_payment = if hand.is_oya { 0 } else { ko_payment };
+        Self::from_parts(
+            hand,
+            AgariType::Tsumo,
+            (oya_payment, ko_payment, total_payment),
+            pao_payment,
+        )
+    }
+
+    // the table's riichi deposits, paid out to the winner
+    pub fn riichi_bonus(&self) -> u32 {
+        self.riichi_sticks as u32 * 1000
+    }
+
+    // limit name, real yakuman carry their multiple: "Yakuman x2"
+    pub fn limit_label(&self) -> Option<String> {
+        match &self.limit_name {
+            Some(HandLimit::Yakuman) if self.han > 13 => {
+                Some(format!("Yakuman x{}", self.han / 13))
+            }
+            limit => limit.as_ref().map(|l| l.name().to_string()),
+        }
+    }
+
+    // (han, fu, total_payment), for comparing against score tables
+    pub fn summary(&self) -> (u8, u8, u32) {
+        (self.han, self.fu, self.total_payment)
+    }
+
+    fn from_parts(
+        hand: ScoredHand,
+        agari_type: AgariType,
+        (oya_payment, ko_payment, total_payment): (u32, u32, u32),
+        pao_payment: u32,
+    ) -> Self {
+        AgariResult {
+            han: hand.han,
+            fu: hand.fu,
+            yaku_list: hand.yaku_list,
+            num_akadora: hand.num_akadora,
+            limit_name: hand.limit_name,
+            oya_payment,
+            ko_payment,
+            total_payment,
+            honba: hand.honba,
+            riichi_sticks: hand.riichi_sticks,
+            agari_type,
+            is_oya: hand.is_oya,
+            hand_structure: hand.hand_structure,
+            pao_payment,
+            dora_breakdown: hand.dora_breakdown,
+            is_furiten: false,
+        }
+    }
+}
+
+// what differs between two results, one line each, empty when they score the same
+pub fn score_diff(a: &AgariResult, b: &AgariResult) -> Vec<String> {
+    let mut diffs = Vec::new();
+    let mut field = |name: &str, x: u32, y: u32| {
+        if x != y {
+            diffs.push(format!("{}: {} vs {}", name, x, y));
+        }
+    };
+    field("han", a.han as u32, b.han as u32);
+    field("fu", a.fu as u32, b.fu as u32);
+    field("oya_payment", a.oya_payment, b.oya_payment);
+    field("ko_payment", a.ko_payment, b.ko_payment);
+    field("total_payment", a.total_payment, b.total_payment);
+    field("pao_payment", a.pao_payment, b.pao_payment);
+
+    if a.limit_name != b.limit_name {
+        diffs.push(format!("limit: {:?} vs {:?}", a.limit_name, b.limit_name));
+    }
+
+    // yaku compared as multisets, so Dora x2 vs Dora x1 shows up
+    let mut seen: Vec<Yaku> = Vec::new();
+    for yaku in a.yaku_list.iter().chain(&b.yaku_list) {
+        if seen.contains(yaku) {
+            continue;
+        }
+        seen.push(*yaku);
+        let count = |list: &[Yaku]| list.iter().filter(|y| *y == yaku).count();
+        let (x, y) = (count(&a.yaku_list), count(&b.yaku_list));
+        if x != y {
+            diffs.push(format!("{:?}: x{} vs x{}", yaku, x, y));
+        }
+    }
+    diffs
+}
+
+// plain-text summary, used by the CLI
+impl fmt::Display for AgariResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let count = |target: Yaku| self.yaku_list.iter().filter(|y| **y == target).count();
+        let mut names: Vec<String> = self
+            .yaku_list
+            .iter()
+            .filter(|y| !matches!(y, Yaku::Dora | Yaku::UraDora | Yaku::AkaDora))
+            .map(|y| format!("{:?}", y))
+            .collect();
+        for (label, n) in [
+            ("Dora", count(Yaku::Dora)),
+            ("Ura Dora", count(Yaku::UraDora)),
+            ("Aka Dora", count(Yaku::AkaDora)),
+        ] {
+            if n > 0 {
+                names.push(format!("{} x{}", label, n));
+            }
+        }
+        writeln!(f, "{}", names.join(", "))?;
+
+        match &self.limit_name {
+            Some(HandLimit::Yakuman) => writeln!(f, "{}", self.limit_label().unwrap())?,
+            Some(limit) => writeln!(f, "{} han {} fu ({})", self.han, self.fu, limit.name())?,
+            None => writeln!(f, "{} han {} fu", self.han, self.fu)?,
+        }
+
+        let dealer = if self.is_oya { "Dealer" } else { "Non-Dealer" };
+        match (self.agari_type, self.is_oya) {
+            (AgariType::Tsumo, true) => write!(f, "{} Tsumo: {} all", dealer, self.oya_payment)?,
+            (AgariType::Tsumo, false) => write!(
+                f,
+                "{} Tsumo: {} / {}",
+                dealer, self.ko_payment, self.oya_payment
+            )?,
+            (AgariType::Ron, _) => write!(f, "{} Ron: {}", dealer, self.total_payment)?,
+        }
+        if self.honba > 0 {
+            write!(f, " ({} honba)", self.honba)?;
+        }
+        writeln!(f)?;
+        if self.is_furiten && self.agari_type == AgariType::Ron {
+            writeln!(f, "Furiten: a wait is in the discards, ron is not allowed")?;
+        }
+        if self.pao_payment > 0 {
+            writeln!(f, "Pao: {}", self.pao_payment)?;
+        }
+        if self.riichi_sticks > 0 {
+            writeln!(f, "Riichi sticks: {}", self.riichi_bonus())?;
+        }
+        write!(f, "Total: {}", self.total_payment)
+    }
+}
+
+// human-readable steps behind an AgariResult, see calculate_agari_explained
+#[derive(Debug, Clone, Default)]
+pub struct ScoreTrace {
+    pub steps: Vec<String>,
+}
+
+impl fmt::Display for ScoreTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.steps.join("\n"))
+    }
 }
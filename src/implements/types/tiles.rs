@@ -1,4 +1,5 @@
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Suit {
     // 数牌 (Number)
     Manzu, // 萬子 (Characters)
@@ -6,22 +7,31 @@ pub enum Suit {
     Souzu, // 索子 (Bamboo)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Kaze {
     // 風牌 (Wind)
-    Ton,  // 東 (East)
+    #[default]
+    Ton, // 東 (East)
     Nan,  // 南 (South)
     Shaa, // 西 (West)
     Pei,  // 北 (North)
 }
 
-impl Default for Kaze {
-    fn default() -> Self {
-        Kaze::Ton
+impl Kaze {
+    // E -> S -> W -> N -> E, the dora and seat rotation order
+    pub fn next_dora(&self) -> Kaze {
+        match self {
+            Kaze::Ton => Kaze::Nan,
+            Kaze::Nan => Kaze::Shaa,
+            Kaze::Shaa => Kaze::Pei,
+            Kaze::Pei => Kaze::Ton,
+        }
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Sangenpai {
     // 三元牌 (Dragon)
     Haku,  // 白 (White)
@@ -29,20 +39,48 @@ pub enum Sangenpai {
     Chun,  // 中 (Red)
 }
 
+impl Sangenpai {
+    // Haku -> Hatsu -> Chun -> Haku
+    pub fn next_dora(&self) -> Sangenpai {
+        match self {
+            Sangenpai::Haku => Sangenpai::Hatsu,
+            Sangenpai::Hatsu => Sangenpai::Chun,
+            Sangenpai::Chun => Sangenpai::Haku,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Jihai {
     // 字牌 (Honor)
     Kaze(Kaze),
     Sangen(Sangenpai),
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Suhai {
     pub number: u8,
     pub suit: Suit,
 }
 
+// suit first, then number, so 1m < 9m < 1p (a derive would compare number first)
+impl Ord for Suhai {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.suit, self.number).cmp(&(other.suit, other.number))
+    }
+}
+
+impl PartialOrd for Suhai {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// the derived order matches tile_to_index: man < pin < sou < winds < dragons
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Hai {
     // 牌 (Tile)
     Suhai(Suhai), // 数牌 (Number, 1-9)
@@ -50,6 +88,20 @@ pub enum Hai {
 }
 
 impl Hai {
+    // the 34 tile types in tile_to_index order
+    pub fn all() -> impl Iterator<Item = Hai> {
+        (0..34).map(index_to_tile)
+    }
+
+    // compact wire form, the tile_to_index value (0-33)
+    pub fn to_u8(&self) -> u8 {
+        tile_to_index(self) as u8
+    }
+
+    pub fn from_u8(byte: u8) -> Option<Hai> {
+        (byte < 34).then(|| index_to_tile(byte as usize))
+    }
+
     // simple (2-8)
     pub fn is_simple(&self) -> bool {
         match self {
@@ -75,6 +127,14 @@ impl Hai {
     pub fn is_yaochuu(&self) -> bool {
         self.is_terminal() || self.is_jihai()
     }
+
+    // number tile within 1-9, always true for honors
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Hai::Suhai(Suhai { number: n, .. }) => (1..=9).contains(n),
+            Hai::Jihai(_) => true,
+        }
+    }
 }
 
 pub fn tile_to_index(tile: &Hai) -> usize {
@@ -125,3 +185,97 @@ pub fn index_to_tile(index: usize) -> Hai {
         _ => panic!("Invalid tile index: {}", index),
     }
 }
+
+// canonical display order: man, pin, sou, winds, dragons, each ascending (the tile_to_index order)
+pub fn sort_tiles(tiles: &mut [Hai]) {
+    tiles.sort_by_key(tile_to_index);
+}
+
+// one byte per tile, so a full hand is 14 bytes
+pub fn tiles_to_bytes(tiles: &[Hai]) -> Vec<u8> {
+    tiles.iter().map(Hai::to_u8).collect()
+}
+
+// None if any byte is not a tile
+pub fn tiles_from_bytes(bytes: &[u8]) -> Option<Vec<Hai>> {
+    bytes.iter().map(|&b| Hai::from_u8(b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implements::notation::parse_hand;
+    use crate::implements::yaku_checkers::utils::get_dora_tile;
+
+    #[test]
+    fn wind_dora_cycle() {
+        let mut kaze = Kaze::Ton;
+        let mut seen = Vec::new();
+        for _ in 0..4 {
+            seen.push(kaze);
+            kaze = kaze.next_dora();
+        }
+        assert_eq!(seen, [Kaze::Ton, Kaze::Nan, Kaze::Shaa, Kaze::Pei]);
+        assert_eq!(kaze, Kaze::Ton);
+    }
+
+    #[test]
+    fn dragon_dora_cycle() {
+        assert_eq!(Sangenpai::Haku.next_dora(), Sangenpai::Hatsu);
+        assert_eq!(Sangenpai::Hatsu.next_dora(), Sangenpai::Chun);
+        assert_eq!(Sangenpai::Chun.next_dora(), Sangenpai::Haku);
+    }
+
+    #[test]
+    fn honor_indicators_follow_the_cycle() {
+        // 1z-4z then 5z-7z, each group wrapping to its own first tile
+        let dora: Vec<usize> = (27..34)
+            .map(|i| tile_to_index(&get_dora_tile(&index_to_tile(i))))
+            .collect();
+        assert_eq!(dora, [28, 29, 30, 27, 32, 33, 31]);
+    }
+
+    #[test]
+    fn all_yields_34_distinct_tiles_in_index_order() {
+        let all: Vec<Hai> = Hai::all().collect();
+        assert_eq!(all.len(), 34);
+        for (i, tile) in all.iter().enumerate() {
+            assert_eq!(tile_to_index(tile), i);
+        }
+        let distinct: std::collections::HashSet<Hai> = all.iter().copied().collect();
+        assert_eq!(distinct.len(), 34);
+        assert_eq!(all[0], Hai::try_from("1m").unwrap());
+        assert_eq!(all[33], Hai::try_from("7z").unwrap());
+    }
+
+    #[test]
+    fn byte_encoding_round_trips() {
+        for tile in Hai::all() {
+            assert_eq!(Hai::from_u8(tile.to_u8()), Some(tile));
+        }
+        assert_eq!(Hai::from_u8(34), None);
+
+        let hand = parse_hand("123m456p789s11z999m").unwrap();
+        let bytes = tiles_to_bytes(&hand);
+        assert_eq!(bytes.len(), 14);
+        assert_eq!(bytes[..3], [0, 1, 2]);
+        assert_eq!(tiles_from_bytes(&bytes), Some(hand));
+        assert_eq!(tiles_from_bytes(&[0, 34]), None);
+    }
+
+    #[test]
+    fn sort_tiles_orders_man_pin_sou_honors() {
+        let mut hand = parse_hand("7z1s9m2z5p1m9s3p5z").unwrap();
+        sort_tiles(&mut hand);
+        assert_eq!(hand, parse_hand("19m35p19s257z").unwrap());
+    }
+
+    #[test]
+    fn suit_orders_before_number() {
+        let t = |s: &str| Hai::try_from(s).unwrap();
+        assert!(t("1m") < t("9m") && t("9m") < t("1p"));
+        assert!(t("9p") < t("1s"));
+        // honors follow every number tile, winds before dragons
+        assert!(t("9s") < t("1z") && t("4z") < t("5z") && t("5z") < t("7z"));
+    }
+}
@@ -1,4 +1,5 @@
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Yaku {
     // 1 Han
     Riichi,           // 立直 (Riichi)
@@ -54,8 +55,42 @@ pub enum Yaku {
     KokushiMusouJusanmen, // 国士無S双13面待ち (13-Sided Wait Kokushi)
     JunseiChuurenPoutou,  // 純正九蓮宝燈 (True Nine Gates)
 
+    // Local Yakuman (RuleConfig::local_yakuman)
+    Daisharin,   // 大車輪 (Big Wheels, 2-8 pinzu pairs)
+    Daichikurin, // 大竹林 (Bamboo Forest, 2-8 souzu pairs)
+    Daisuurin,   // 大数隣 (Numerous Neighbours, 2-8 manzu pairs)
+
     // Dora (not Yaku)
     Dora,    // ドラ (Dora)
     UraDora, // 裏ドラ (Ura Dora)
     AkaDora, // 赤ドラ (Red Five Dora)
 }
+
+impl Yaku {
+    // closed hands only, never valid once a meld is called
+    pub fn is_menzen_only(&self) -> bool {
+        matches!(
+            self,
+            Yaku::Riichi
+                | Yaku::DaburuRiichi
+                | Yaku::Ippatsu
+                | Yaku::MenzenTsumo
+                | Yaku::Pinfu
+                | Yaku::Iipeikou
+                | Yaku::Ryanpeikou
+                | Yaku::Chiitoitsu
+                | Yaku::Tenhou
+                | Yaku::Chiihou
+                | Yaku::Renhou
+                | Yaku::Suuankou
+                | Yaku::SuuankouTanki
+                | Yaku::KokushiMusou
+                | Yaku::KokushiMusouJusanmen
+                | Yaku::ChuurenPoutou
+                | Yaku::JunseiChuurenPoutou
+                | Yaku::Daisharin
+                | Yaku::Daichikurin
+                | Yaku::Daisuurin
+        )
+    }
+}
@@ -1,6 +1,8 @@
+use crate::implements::NOT_A_WINNING_HAND;
 use crate::implements::types::{
     game::{AgariType, GameContext, PlayerContext},
     hand::{HandOrganization, HandStructure},
+    scoring::DoraBreakdown,
     yaku::Yaku,
 };
 
@@ -17,6 +19,7 @@ pub struct YakuResult {
     pub hand_structure: HandStructure,
     pub yaku_list: Vec<Yaku>,
     pub num_akadora: u8,
+    pub dora_breakdown: DoraBreakdown,
 }
 
 pub fn check_all_yaku(
@@ -36,6 +39,9 @@ pub fn check_all_yaku(
         };
 
     yakuman_list.extend(hand_yakuman);
+    if !player.is_menzen {
+        yakuman_list.retain(|y| !y.is_menzen_only());
+    }
 
     if !yakuman_list.is_empty() {
         let final_yakuman = post_process_yakuman(yakuman_list);
@@ -44,6 +50,7 @@ pub fn check_all_yaku(
             hand_structure,
             yaku_list: final_yakuman,
             num_akadora: 0,
+            dora_breakdown: DoraBreakdown::default(),
         });
     }
 
@@ -59,39 +66,42 @@ pub fn check_all_yaku(
         } => find_chiitoitsu_yaku(pairs, agari_hai, machi, player, game, agari_type),
         _ => vec![],
     };
+    regular_yaku = dedup_exclusive_yaku(regular_yaku);
+    // an open hand never keeps a closed-only yaku, whatever the checkers were fed
+    if !player.is_menzen {
+        regular_yaku.retain(|y| !y.is_menzen_only());
+    }
 
     // Dora
     let has_yaku = !regular_yaku.is_empty() || player.is_riichi || player.is_daburu_riichi;
 
     let mut num_akadora_to_add = 0;
+    let mut dora_breakdown = DoraBreakdown::default();
 
     if has_yaku {
-        let all_tiles = get_all_tiles_from_structure(&hand_structure);
-
-        let dora_count = count_dora(&all_tiles, &game.dora_indicators);
-        for _ in 0..dora_count {
-            regular_yaku.push(Yaku::Dora);
-        }
-
-        if (player.is_riichi || player.is_daburu_riichi) && !game.uradora_indicators.is_empty() {
-            let uradora_count = count_dora(&all_tiles, &game.uradora_indicators);
-            for _ in 0..uradora_count {
-                regular_yaku.push(Yaku::UraDora);
-            }
-        }
-
-        if game.num_akadora > 0 {
-            num_akadora_to_add = game.num_akadora;
-            for _ in 0..game.num_akadora {
-                regular_yaku.push(Yaku::AkaDora);
-            }
-        }
+        dora_breakdown = count_dora_breakdown(&hand_structure.tiles(), player, game);
+        num_akadora_to_add = dora_breakdown.aka;
+        regular_yaku.extend(
+            std::iter::repeat_n(
+                Yaku::Dora,
+                (dora_breakdown.dora + dora_breakdown.kan_dora) as usize,
+            )
+            .chain(std::iter::repeat_n(
+                Yaku::UraDora,
+                dora_breakdown.ura as usize,
+            ))
+            .chain(std::iter::repeat_n(
+                Yaku::AkaDora,
+                dora_breakdown.aka as usize,
+            )),
+        );
     }
 
     Ok(YakuResult {
         hand_structure,
         yaku_list: regular_yaku,
         num_akadora: num_akadora_to_add,
+        dora_breakdown,
     })
 }
 
@@ -124,11 +134,14 @@ fn resolve_hand_structure(
                 Ok((kokushi_structure, vec![kokushi_yaku]))
             }
             // Chiitoitsu
-            else if let Some(chiitoitsu_structure) = check_chiitoitsu(&counts, agari_hai) {
-                let yakuman = check_chiitoitsu_yakuman(&chiitoitsu_structure);
+            else if let Some(chiitoitsu_structure) =
+                check_chiitoitsu(&counts, agari_hai, game.rules.chiitoitsu_allow_four)
+            {
+                let yakuman = check_chiitoitsu_yakuman(&chiitoitsu_structure, game);
                 Ok((chiitoitsu_structure, yakuman))
             } else {
-                Err("No Yaku Found")
+                // neither a standard shape nor a valid irregular one
+                Err(NOT_A_WINNING_HAND)
             }
         }
     }
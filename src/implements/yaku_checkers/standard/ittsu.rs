@@ -4,7 +4,7 @@ use crate::implements::types::{
 };
 use std::collections::{HashMap, HashSet};
 
-pub fn check_ittsu<'a>(shuntsu: &[&'a Mentsu]) -> bool {
+pub fn check_ittsu(shuntsu: &[&Mentsu]) -> bool {
     if shuntsu.len() < 3 {
         return false;
     }
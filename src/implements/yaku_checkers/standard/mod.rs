@@ -61,13 +61,14 @@ pub fn find_standard_yaku(
     // Yakuhai
     yaku_list.extend(check_yakuhai(hand, player, game));
 
-    // Pinfu
+    // Pinfu: a Rinshan win always holds a kan so check_pinfu rejects it,
+    // Chankan is a ron on a ryanmen wait and keeps Pinfu
     if check_pinfu(hand, player, game) {
         yaku_list.push(Yaku::Pinfu);
     }
 
-    // Tanyao
-    if check_tanyao(hand) {
+    // Tanyao, open only with kuitan
+    if check_tanyao(hand) && (player.is_menzen || game.rules.kuitan) {
         yaku_list.push(Yaku::Tanyao);
     }
 
@@ -100,11 +101,11 @@ pub fn find_standard_yaku(
 
     if koutsu + kantsu == 4 {
         yaku_list.push(Yaku::Toitoi);
-    } else {
-        let concealed_koutsu = count_concealed_koutsu(hand, agari_type);
-        if concealed_koutsu == 3 {
-            yaku_list.push(Yaku::Sanankou);
-        }
+    }
+
+    // stacks with Toitoi
+    if count_concealed_koutsu(hand, agari_type) == 3 {
+        yaku_list.push(Yaku::Sanankou);
     }
 
     if kantsu == 3 {
@@ -148,12 +149,25 @@ pub fn find_standard_yaku(
         }
     }
 
-    if yaku_list.contains(&Yaku::Pinfu) {
-        if yaku_list.contains(&Yaku::RinshanKaihou) || yaku_list.contains(&Yaku::Chankan) {
-            yaku_list.retain(|&y| y != Yaku::Pinfu);
+    yaku_list
+}
+
+// (kept, dropped): the stronger yaku replaces the weaker one
+const EXCLUSIVE_YAKU: [(Yaku, Yaku); 5] = [
+    (Yaku::Junchan, Yaku::Chanta),
+    (Yaku::Honroutou, Yaku::Chanta),
+    (Yaku::Chinitsu, Yaku::Honitsu),
+    (Yaku::Ryanpeikou, Yaku::Iipeikou),
+    (Yaku::DaburuRiichi, Yaku::Riichi),
+];
+
+// Toitoi and Sanankou are not listed: they stack
+pub fn dedup_exclusive_yaku(mut yaku_list: Vec<Yaku>) -> Vec<Yaku> {
+    for (kept, dropped) in EXCLUSIVE_YAKU {
+        if yaku_list.contains(&kept) {
+            yaku_list.retain(|&y| y != dropped);
         }
     }
-
     yaku_list
 }
 
@@ -215,3 +229,27 @@ pub fn find_chiitoitsu_yaku(
 
     yaku_list
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stronger_yaku_drops_the_weaker_one() {
+        for (kept, dropped) in EXCLUSIVE_YAKU {
+            assert_eq!(
+                dedup_exclusive_yaku(vec![dropped, Yaku::Tanyao, kept]),
+                vec![Yaku::Tanyao, kept]
+            );
+            assert_eq!(dedup_exclusive_yaku(vec![dropped]), vec![dropped]);
+        }
+    }
+
+    #[test]
+    fn toitoi_and_sanankou_stack() {
+        assert_eq!(
+            dedup_exclusive_yaku(vec![Yaku::Toitoi, Yaku::Sanankou]),
+            vec![Yaku::Toitoi, Yaku::Sanankou]
+        );
+    }
+}
@@ -30,10 +30,10 @@ pub fn check_pinfu(hand: &AgariHand, player: &PlayerContext, game: &GameContext)
     if let Hai::Jihai(Jihai::Sangen(_)) = hand.atama.0 {
         return false;
     }
-    if let Hai::Jihai(Jihai::Kaze(k)) = hand.atama.0 {
-        if k == game.bakaze || k == player.jikaze {
-            return false;
-        }
+    if let Hai::Jihai(Jihai::Kaze(k)) = hand.atama.0
+        && (k == game.bakaze || k == player.jikaze || !game.rules.pinfu_guest_wind_ok)
+    {
+        return false;
     }
     // Ryanmen check
     if hand.machi != Machi::Ryanmen {
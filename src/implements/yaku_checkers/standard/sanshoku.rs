@@ -28,7 +28,7 @@ pub fn check_sanshoku_generic(mentsu_list: &[&Mentsu]) -> bool {
     starters.values().any(|&(m, p, s)| m && p && s)
 }
 
-pub fn check_sanshoku_doujun<'a>(shuntsu: &[&'a Mentsu]) -> bool {
+pub fn check_sanshoku_doujun(shuntsu: &[&Mentsu]) -> bool {
     check_sanshoku_generic(shuntsu)
 }
 
@@ -1,12 +1,18 @@
 // utils.rs: utility functions for yaku checkers
 
 use crate::implements::types::{
-    game::AgariType,
+    game::{AgariType, GameContext, PlayerContext},
     hand::{AgariHand, HandStructure, Machi, Mentsu, MentsuType},
-    tiles::{Hai, Jihai, Kaze, Sangenpai, Suhai, Suit, index_to_tile},
+    scoring::DoraBreakdown,
+    tiles::{Hai, Jihai, Sangenpai, Suhai, Suit, index_to_tile},
 };
 
-pub fn check_chiitoitsu(counts: &[u8; 34], agari_hai: Hai) -> Option<HandStructure> {
+// seven distinct pairs, a four of a kind only counts as two when allow_four is set
+pub fn check_chiitoitsu(
+    counts: &[u8; 34],
+    agari_hai: Hai,
+    allow_four: bool,
+) -> Option<HandStructure> {
     let mut pair_count = 0;
     let mut pairs = Vec::new();
 
@@ -16,7 +22,7 @@ pub fn check_chiitoitsu(counts: &[u8; 34], agari_hai: Hai) -> Option<HandStructu
                 pair_count += 1;
                 let tile = index_to_tile(idx);
                 pairs.push((tile, tile));
-            } else if count == 4 {
+            } else if count == 4 && allow_four {
                 pair_count += 2;
                 let tile = index_to_tile(idx);
                 pairs.push((tile, tile));
@@ -38,6 +44,33 @@ pub fn check_chiitoitsu(counts: &[u8; 34], agari_hai: Hai) -> Option<HandStructu
     }
 }
 
+// dora han by source for the hand's 14+ tiles
+pub fn count_dora_breakdown(
+    all_tiles: &[Hai],
+    player: &PlayerContext,
+    game: &GameContext,
+) -> DoraBreakdown {
+    let mut breakdown = DoraBreakdown::default();
+
+    // first indicator is the table dora, the rest were revealed by kans
+    if let Some((table, kan)) = game.dora_indicators.split_first() {
+        breakdown.dora = count_dora(all_tiles, std::slice::from_ref(table));
+        breakdown.kan_dora = count_dora(all_tiles, kan);
+    }
+
+    if player.is_riichi || player.is_daburu_riichi {
+        // one ura per dora flip, extras only get here with lenient_dora_count
+        let num_ura = game
+            .uradora_indicators
+            .len()
+            .min(game.dora_indicators.len());
+        breakdown.ura = count_dora(all_tiles, &game.uradora_indicators[..num_ura]);
+    }
+
+    breakdown.aka = game.num_akadora;
+    breakdown
+}
+
 pub fn count_dora(all_tiles: &[Hai], indicators: &[Hai]) -> u8 {
     let mut count = 0;
     for indicator in indicators {
@@ -66,17 +99,8 @@ pub fn get_dora_tile(indicator: &Hai) -> Hai {
                 })
             }
         }
-        Hai::Jihai(Jihai::Kaze(k)) => Hai::Jihai(Jihai::Kaze(match k {
-            Kaze::Ton => Kaze::Nan,
-            Kaze::Nan => Kaze::Shaa,
-            Kaze::Shaa => Kaze::Pei,
-            Kaze::Pei => Kaze::Ton,
-        })),
-        Hai::Jihai(Jihai::Sangen(s)) => Hai::Jihai(Jihai::Sangen(match s {
-            Sangenpai::Haku => Sangenpai::Hatsu,
-            Sangenpai::Hatsu => Sangenpai::Chun,
-            Sangenpai::Chun => Sangenpai::Haku,
-        })),
+        Hai::Jihai(Jihai::Kaze(k)) => Hai::Jihai(Jihai::Kaze(k.next_dora())),
+        Hai::Jihai(Jihai::Sangen(s)) => Hai::Jihai(Jihai::Sangen(s.next_dora())),
     }
 }
 
@@ -85,45 +109,16 @@ pub fn get_all_tiles(hand: &AgariHand) -> Vec<Hai> {
     tiles.push(hand.atama.0);
     tiles.push(hand.atama.1);
     for mentsu in &hand.mentsu {
-        match mentsu.mentsu_type {
-            MentsuType::Shuntsu | MentsuType::Koutsu => {
-                tiles.extend_from_slice(&mentsu.tiles[0..3]);
-            }
-            MentsuType::Kantsu => {
-                tiles.extend_from_slice(&mentsu.tiles[0..4]);
-            }
-        }
+        tiles.extend_from_slice(mentsu.tiles_slice());
     }
     tiles
 }
 
-pub fn get_all_tiles_from_structure(structure: &HandStructure) -> Vec<Hai> {
-    match structure {
-        HandStructure::YonmentsuIchiatama(hand) => get_all_tiles(hand),
-        HandStructure::Chiitoitsu { pairs, .. } => {
-            pairs.iter().flat_map(|&(t1, t2)| vec![t1, t2]).collect()
-        }
-        HandStructure::KokushiMusou { tiles, atama, .. } => {
-            let mut v = tiles.to_vec();
-            v.push(atama.0);
-            v
-        }
-        HandStructure::ChuurenPoutou { hand, .. } => get_all_tiles(hand),
-    }
-}
-
 pub fn get_all_groups(hand: &AgariHand) -> Vec<Vec<Hai>> {
     let mut groups = Vec::with_capacity(5);
     groups.push(vec![hand.atama.0, hand.atama.1]);
     for mentsu in &hand.mentsu {
-        match mentsu.mentsu_type {
-            MentsuType::Shuntsu | MentsuType::Koutsu => {
-                groups.push(mentsu.tiles[0..3].to_vec());
-            }
-            MentsuType::Kantsu => {
-                groups.push(mentsu.tiles[0..4].to_vec());
-            }
-        }
+        groups.push(mentsu.tiles_slice().to_vec());
     }
     groups
 }
@@ -149,10 +144,12 @@ pub fn count_concealed_koutsu(hand: &AgariHand, agari_type: AgariType) -> u8 {
         }
 
         if m.mentsu_type == MentsuType::Koutsu {
-            if agari_type == AgariType::Ron {
-                if m.tiles[0] == hand.agari_hai {
-                    continue;
-                }
+            // ron only opens the triplet it completed on a shanpon wait
+            if agari_type == AgariType::Ron
+                && hand.machi == Machi::Shanpon
+                && m.tiles[0] == hand.agari_hai
+            {
+                continue;
             }
             count += 1;
         } else if m.mentsu_type == MentsuType::Kantsu {
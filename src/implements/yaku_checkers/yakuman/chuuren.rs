@@ -1,6 +1,6 @@
 use crate::implements::{
     types::{
-        hand::AgariHand,
+        hand::{AgariHand, MentsuType},
         tiles::{Hai, Suhai},
     },
     yaku_checkers::{standard::color::check_chinitsu, utils::get_all_tiles},
@@ -15,16 +15,21 @@ pub fn check_chuuren(hand: &AgariHand) -> Option<bool> {
     }
     let suit = suit.unwrap();
 
-    if !hand.mentsu.iter().all(|m| !m.is_minchou) {
+    // fully concealed and kan-free, an ankan breaks the 14-tile shape too
+    if hand
+        .mentsu
+        .iter()
+        .any(|m| m.is_minchou || m.mentsu_type == MentsuType::Kantsu)
+    {
         return None;
     }
 
     let mut counts = [0u8; 9];
     for tile in &all_tiles {
-        if let Hai::Suhai(Suhai { number: n, suit: s }) = tile {
-            if *s == suit {
-                counts[(n - 1) as usize] += 1;
-            }
+        if let Hai::Suhai(Suhai { number: n, suit: s }) = tile
+            && *s == suit
+        {
+            counts[(n - 1) as usize] += 1;
         }
     }
 
@@ -52,10 +57,11 @@ pub fn check_chuuren(hand: &AgariHand) -> Option<bool> {
     if !has_extra {
         return None;
     }
-    if let Hai::Suhai(Suhai { number: n, suit: s }) = hand.agari_hai {
-        if s == suit && n as usize == extra_tile_num {
-            return Some(true);
-        }
+    if let Hai::Suhai(Suhai { number: n, suit: s }) = hand.agari_hai
+        && s == suit
+        && n as usize == extra_tile_num
+    {
+        return Some(true);
     }
 
     Some(false)
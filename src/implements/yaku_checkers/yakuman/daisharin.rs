@@ -0,0 +1,31 @@
+use crate::implements::{
+    types::{
+        tiles::{Hai, Suhai, Suit},
+        yaku::Yaku,
+    },
+    yaku_checkers::standard::color::check_chinitsu,
+};
+
+// local yakuman: 22334455667788 in one suit, read as chiitoitsu or ryanpeikou
+pub fn check_daisharin(all_tiles: &[Hai]) -> Option<Yaku> {
+    let (is_chinitsu, suit) = check_chinitsu(all_tiles);
+    if !is_chinitsu || all_tiles.len() != 14 {
+        return None;
+    }
+
+    let mut counts = [0u8; 9];
+    for tile in all_tiles {
+        if let Hai::Suhai(Suhai { number: n, .. }) = tile {
+            counts[(n - 1) as usize] += 1;
+        }
+    }
+    if counts != [0, 2, 2, 2, 2, 2, 2, 2, 0] {
+        return None;
+    }
+
+    match suit? {
+        Suit::Pinzu => Some(Yaku::Daisharin),
+        Suit::Souzu => Some(Yaku::Daichikurin),
+        Suit::Manzu => Some(Yaku::Daisuurin),
+    }
+}
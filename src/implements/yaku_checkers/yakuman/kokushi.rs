@@ -50,11 +50,9 @@ pub fn check_kokushi(counts: &[u8; 34], agari_hai: Hai) -> Option<(HandStructure
     let mut yaku = Yaku::KokushiMusou;
     let mut final_machi = Machi::KokushiIchimen;
 
-    if atama.0 == agari_hai {
-        if tiles.len() == 13 {
-            yaku = Yaku::KokushiMusouJusanmen;
-            final_machi = Machi::KokushiJusanmen;
-        }
+    if atama.0 == agari_hai && tiles.len() == 13 {
+        yaku = Yaku::KokushiMusouJusanmen;
+        final_machi = Machi::KokushiJusanmen;
     }
 
     Some((
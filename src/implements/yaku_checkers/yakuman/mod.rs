@@ -1,8 +1,9 @@
 pub mod chuuren;
+pub mod daisharin;
 pub mod kokushi;
 
-use self::chuuren::check_chuuren;
 pub use self::kokushi::check_kokushi;
+use self::{chuuren::check_chuuren, daisharin::check_daisharin};
 
 use super::utils::*;
 use crate::implements::types::{
@@ -28,8 +29,8 @@ pub fn check_game_state_yakuman(_player: &PlayerContext, game: &GameContext) ->
 
 pub fn check_standard_yakuman(
     hand: &AgariHand,
-    _player: &PlayerContext,
-    _game: &GameContext,
+    player: &PlayerContext,
+    game: &GameContext,
     agari_type: AgariType,
 ) -> (Vec<Yaku>, Option<bool>) {
     let mut yakuman = Vec::new();
@@ -61,6 +62,12 @@ pub fn check_standard_yakuman(
     if is_ryuuiisou {
         yakuman.push(Yaku::Ryuuiisou);
     }
+    if game.rules.local_yakuman
+        && player.is_menzen
+        && let Some(daisharin) = check_daisharin(&all_tiles)
+    {
+        yakuman.push(daisharin);
+    }
 
     //  Meld-based Yakuman
     let (_koutsu, kantsu) = count_koutsu_kantsu(hand);
@@ -83,10 +90,10 @@ pub fn check_standard_yakuman(
     // Daisangen
     let mut dragon_koutsu = 0;
     for mentsu in &hand.mentsu {
-        if is_koutsu_or_kantsu(mentsu) {
-            if let Hai::Jihai(Jihai::Sangen(_)) = mentsu.tiles[0] {
-                dragon_koutsu += 1;
-            }
+        if is_koutsu_or_kantsu(mentsu)
+            && let Hai::Jihai(Jihai::Sangen(_)) = mentsu.tiles[0]
+        {
+            dragon_koutsu += 1;
         }
     }
     if dragon_koutsu == 3 {
@@ -97,10 +104,10 @@ pub fn check_standard_yakuman(
     let mut wind_koutsu = 0;
     let mut wind_atama = false;
     for mentsu in &hand.mentsu {
-        if is_koutsu_or_kantsu(mentsu) {
-            if let Hai::Jihai(Jihai::Kaze(_)) = mentsu.tiles[0] {
-                wind_koutsu += 1;
-            }
+        if is_koutsu_or_kantsu(mentsu)
+            && let Hai::Jihai(Jihai::Kaze(_)) = mentsu.tiles[0]
+        {
+            wind_koutsu += 1;
         }
     }
     if let Hai::Jihai(Jihai::Kaze(_)) = hand.atama.0 {
@@ -126,9 +133,16 @@ pub fn check_standard_yakuman(
     (yakuman, chuuren_flag)
 }
 
-/// Tsuuiisou
-pub fn check_chiitoitsu_yakuman(hand: &HandStructure) -> Vec<Yaku> {
+/// Tsuuiisou, Daisharin
+pub fn check_chiitoitsu_yakuman(hand: &HandStructure, game: &GameContext) -> Vec<Yaku> {
     if let HandStructure::Chiitoitsu { pairs, .. } = hand {
+        if game.rules.local_yakuman {
+            let all_tiles: Vec<Hai> = pairs.iter().flat_map(|&(t1, t2)| [t1, t2]).collect();
+            if let Some(daisharin) = check_daisharin(&all_tiles) {
+                return vec![daisharin];
+            }
+        }
+
         let mut is_tsuuiisou = true;
         for (tile, _) in pairs {
             if !tile.is_jihai() {
@@ -139,6 +153,11 @@ pub fn check_chiitoitsu_yakuman(hand: &HandStructure) -> Vec<Yaku> {
         if is_tsuuiisou {
             return vec![Yaku::Tsuuiisou];
         }
+
+        // only six green kinds exist, so this needs chiitoitsu_allow_four
+        if pairs.iter().all(|(tile, _)| is_green_tile(tile)) {
+            return vec![Yaku::Ryuuiisou];
+        }
     }
     vec![]
 }
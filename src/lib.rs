@@ -0,0 +1,3 @@
+pub mod implements;
+#[cfg(feature = "wasm")]
+pub mod wasm;
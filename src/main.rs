@@ -1,5 +1,6 @@
 mod gui;
-mod implements;
+
+use riichi_mahjong_scoring_calculator::implements;
 
 fn main() -> iced::Result {
     gui::run()
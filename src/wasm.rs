@@ -0,0 +1,16 @@
+use crate::implements::{calculate_agari, input::UserInput};
+use wasm_bindgen::prelude::*;
+
+// UserInput JSON in, AgariResult JSON or {"error": "..."} out
+#[wasm_bindgen]
+pub fn score_hand_json(input_json: &str) -> String {
+    let result = serde_json::from_str::<UserInput>(input_json)
+        .map_err(|e| e.to_string())
+        .and_then(|input| calculate_agari(&input).map_err(str::to_string))
+        .and_then(|result| serde_json::to_string(&result).map_err(|e| e.to_string()));
+
+    match result {
+        Ok(json) => json,
+        Err(e) => serde_json::json!({ "error": e }).to_string(),
+    }
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "cli")]
+
+use std::process::Command;
+
+fn run(args: &[&str]) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_riichi-cli"))
+        .args(args)
+        .output()
+        .expect("riichi-cli runs");
+    (
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+    )
+}
+
+const EXPECTED: &str = "Riichi, MenzenTsumo\n2 han 40 fu\nDealer Tsumo: 1300 all\nTotal: 3900\n";
+
+#[test]
+fn scores_the_documented_invocation() {
+    let (ok, stdout) = run(&[
+        "--hand",
+        "123m456p789s11z999m",
+        "--tsumo",
+        "--riichi",
+        "--dora",
+        "2p",
+    ]);
+    assert!(ok);
+    assert_eq!(stdout, EXPECTED);
+}
+
+#[test]
+fn dora_is_optional() {
+    let (ok, stdout) = run(&["--hand", "123m456p789s11z999m", "--tsumo", "--riichi"]);
+    assert!(ok);
+    assert_eq!(stdout, EXPECTED);
+}
+
+#[test]
+fn explicit_winning_tile_on_ron() {
+    let (ok, stdout) = run(&["--hand", "123m456p789s11z999m", "--win", "9m", "--riichi"]);
+    assert!(ok);
+    assert!(stdout.starts_with("Riichi\n"), "{stdout}");
+}
+
+#[test]
+fn invalid_hand_exits_with_failure() {
+    let (ok, _) = run(&["--hand", "123m456p", "--tsumo"]);
+    assert!(!ok);
+}
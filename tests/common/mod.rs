@@ -0,0 +1,62 @@
+#![allow(dead_code)]
+
+use riichi_mahjong_scoring_calculator::implements::game::{AgariType, GameContext, PlayerContext};
+use riichi_mahjong_scoring_calculator::implements::hand::{HandStructure, Machi};
+use riichi_mahjong_scoring_calculator::implements::input::{OpenMeldInput, UserInput};
+use riichi_mahjong_scoring_calculator::implements::notation::parse_hand;
+use riichi_mahjong_scoring_calculator::implements::scoring::AgariResult;
+use riichi_mahjong_scoring_calculator::implements::tiles::{Hai, Kaze};
+
+pub fn tiles(notation: &str) -> Vec<Hai> {
+    parse_hand(notation).expect("valid notation")
+}
+
+pub fn tile(notation: &str) -> Hai {
+    Hai::try_from(notation).expect("single tile")
+}
+
+// concealed tiles including the winning tile, East dealer in East 1 with default rules
+pub fn hand(concealed: &str, win: &str, agari_type: AgariType) -> UserInput {
+    let mut hand_tiles = tiles(concealed);
+    let winning_tile = tile(win);
+    if agari_type == AgariType::Ron {
+        let i = hand_tiles
+            .iter()
+            .position(|&t| t == winning_tile)
+            .expect("winning tile in hand");
+        hand_tiles.remove(i);
+    }
+    UserInput {
+        hand_tiles,
+        winning_tile,
+        open_melds: Vec::new(),
+        closed_kans: Vec::new(),
+        player_context: PlayerContext::default(),
+        game_context: GameContext::default(),
+        agari_type,
+    }
+}
+
+// same hand for the South seat, a non-dealer
+pub fn south(mut input: UserInput) -> UserInput {
+    input.player_context.jikaze = Kaze::Nan;
+    input.player_context.is_oya = false;
+    input
+}
+
+pub fn with_melds(mut input: UserInput, melds: Vec<OpenMeldInput>) -> UserInput {
+    input.open_melds = melds;
+    input.player_context.is_menzen = false;
+    input
+}
+
+// the wait the scored reading was built on
+pub fn machi(result: &AgariResult) -> Machi {
+    match &result.hand_structure {
+        HandStructure::YonmentsuIchiatama(hand) | HandStructure::ChuurenPoutou { hand, .. } => {
+            hand.machi
+        }
+        HandStructure::Chiitoitsu { machi, .. } => *machi,
+        HandStructure::KokushiMusou { _machi, .. } => *_machi,
+    }
+}
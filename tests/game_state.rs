@@ -0,0 +1,133 @@
+mod common;
+
+use common::{hand, with_melds};
+use riichi_mahjong_scoring_calculator::implements::calculate_agari;
+use riichi_mahjong_scoring_calculator::implements::game::AgariType;
+use riichi_mahjong_scoring_calculator::implements::game_state::GameState;
+use riichi_mahjong_scoring_calculator::implements::input::{OpenMeldInput, UserInput};
+use riichi_mahjong_scoring_calculator::implements::scoring::AgariResult;
+use riichi_mahjong_scoring_calculator::implements::tiles::Kaze;
+use riichi_mahjong_scoring_calculator::implements::yaku::Yaku;
+
+// player 0 deals East 1, so player 1 sits South
+fn score(state: &GameState, player: u8, concealed: &str, win: &str, tsumo: bool) -> AgariResult {
+    let agari_type = if tsumo {
+        AgariType::Tsumo
+    } else {
+        AgariType::Ron
+    };
+    let mut input = hand(concealed, win, agari_type);
+    input.player_context = state.player_context(player);
+    input.game_context = state.game_context();
+    input.player_context.is_riichi = true;
+    calculate_agari(&input).unwrap()
+}
+
+#[test]
+fn non_dealer_tsumo_splits_between_dealer_and_others() {
+    let mut state = GameState::new(25000, Kaze::Nan);
+    let result = score(&state, 1, "123m456p789s11z999m", "9m", true);
+    assert_eq!(result.summary(), (2, 40, 2700));
+    state.apply_agari(1, &result, None, None).unwrap();
+    assert_eq!(state.scores, [23700, 27700, 24300, 24300]);
+    assert_eq!((state.kyoku, state.honba), (2, 0));
+}
+
+#[test]
+fn dealer_ron_keeps_the_deal() {
+    let mut state = GameState::new(25000, Kaze::Nan);
+    state.declare_riichi(0);
+    let result = score(&state, 0, "123m456p789s11z999m", "9m", false);
+    assert_eq!(result.yaku_list, vec![Yaku::Riichi]);
+    assert_eq!(result.summary(), (1, 40, 2000));
+    state.apply_agari(0, &result, Some(2), None).unwrap();
+    assert_eq!(state.scores, [27000, 25000, 23000, 25000]);
+    assert_eq!((state.kyoku, state.honba, state.riichi_bou), (1, 1, 0));
+}
+
+fn dragon_pons(concealed: &str, win: &str, agari_type: AgariType) -> UserInput {
+    let state = GameState::new(25000, Kaze::Nan);
+    let mut input = with_melds(
+        hand(concealed, win, agari_type),
+        vec![
+            OpenMeldInput::pon(common::tile("5z")),
+            OpenMeldInput::pon(common::tile("6z")),
+            OpenMeldInput::pon(common::tile("7z")),
+        ],
+    );
+    input.player_context = state.player_context(1);
+    input.player_context.is_menzen = false;
+    input.game_context.pao_player = Some(Kaze::Shaa);
+    input
+}
+
+#[test]
+fn full_pao_tsumo_is_paid_by_the_liable_player() {
+    let mut state = GameState::new(25000, Kaze::Nan);
+    let result = calculate_agari(&dragon_pons("123m99p", "3m", AgariType::Tsumo)).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Daisangen]);
+    assert_eq!(result.summary(), (13, 0, 32000));
+    assert_eq!(result.pao_payment, result.total_payment);
+
+    assert_eq!(
+        state.apply_agari(1, &result, None, None).unwrap_err(),
+        "A pao payment needs the liable player"
+    );
+    state.apply_agari(1, &result, None, Some(2)).unwrap();
+    assert_eq!(state.scores, [25000, 57000, -7000, 25000]);
+    assert!(state.is_over());
+}
+
+#[test]
+fn pao_ron_splits_the_yakuman_with_the_discarder() {
+    let mut state = GameState::new(25000, Kaze::Nan);
+    let result = calculate_agari(&dragon_pons("123m99p", "3m", AgariType::Ron)).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Daisangen]);
+    assert_eq!(result.summary(), (13, 0, 32000));
+    assert_eq!(result.pao_payment, 16000);
+
+    state.apply_agari(1, &result, Some(3), Some(2)).unwrap();
+    assert_eq!(state.scores, [25000, 57000, 9000, 9000]);
+}
+
+#[test]
+fn pao_tsumo_covers_only_its_own_yakuman() {
+    let mut state = GameState::new(25000, Kaze::Nan);
+    let result = calculate_agari(&dragon_pons("111z22z", "1z", AgariType::Tsumo)).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Tsuuiisou, Yaku::Daisangen]);
+    assert_eq!(result.summary(), (26, 0, 64000));
+    // Daisangen in full from pao, Tsuuiisou shared as a plain tsumo
+    assert_eq!(result.pao_payment, 32000);
+    assert_eq!((result.oya_payment, result.ko_payment), (16000, 8000));
+
+    state.apply_agari(1, &result, None, Some(2)).unwrap();
+    assert_eq!(state.scores, [9000, 89000, -15000, 17000]);
+}
+
+#[test]
+fn result_dealer_flag_must_match_the_winner() {
+    let mut state = GameState::new(25000, Kaze::Nan);
+    let result = score(&state, 0, "123m456p789s11z999m", "9m", true);
+    assert!(result.is_oya);
+    assert_eq!(
+        state.apply_agari(1, &result, None, None).unwrap_err(),
+        "The result's dealer flag does not match the winner's seat"
+    );
+    assert_eq!(state.scores, [25000; 4]);
+}
+
+// the dealer sits East from East 1 through South 4
+
+#[test]
+fn dealer_is_east_through_a_hanchan() {
+    let mut state = GameState::new(25000, Kaze::Nan);
+    for round in [Kaze::Ton, Kaze::Nan] {
+        for kyoku in 1..=4 {
+            assert_eq!((state.bakaze, state.kyoku), (round, kyoku));
+            assert_eq!(state.seat_wind(state.dealer()), Kaze::Ton);
+            assert!(state.player_context(state.dealer()).is_oya);
+            state.apply_ryuukyoku([false; 4]);
+        }
+    }
+    assert!(state.is_over());
+}
@@ -0,0 +1,81 @@
+use proptest::prelude::*;
+use riichi_mahjong_scoring_calculator::implements::calculate_agari;
+use riichi_mahjong_scoring_calculator::implements::game::{AgariType, GameContext, PlayerContext};
+use riichi_mahjong_scoring_calculator::implements::hand::KanType;
+use riichi_mahjong_scoring_calculator::implements::input::{OpenMeldInput, UserInput};
+use riichi_mahjong_scoring_calculator::implements::organize_hand;
+use riichi_mahjong_scoring_calculator::implements::tiles::{Hai, Suhai, Suit, index_to_tile};
+
+// valid tiles plus out-of-range number tiles
+fn any_tile() -> impl Strategy<Value = Hai> {
+    prop_oneof![
+        4 => (0usize..34).prop_map(index_to_tile),
+        1 => (0u8..=12, 0usize..3).prop_map(|(number, suit)| Hai::Suhai(Suhai {
+            number,
+            suit: [Suit::Manzu, Suit::Pinzu, Suit::Souzu][suit],
+        })),
+    ]
+}
+
+fn any_meld() -> impl Strategy<Value = OpenMeldInput> {
+    (any_tile(), 0usize..3).prop_map(|(tile, kind)| match kind {
+        0 => OpenMeldInput::chi(tile),
+        1 => OpenMeldInput::pon(tile),
+        _ => OpenMeldInput::kan(tile, KanType::Daiminkan),
+    })
+}
+
+fn any_input() -> impl Strategy<Value = UserInput> {
+    (
+        prop::collection::vec(any_tile(), 13..=18),
+        any_tile(),
+        prop::collection::vec(any_meld(), 0..=4),
+        prop::collection::vec(any_tile(), 0..=2),
+        any::<bool>(),
+    )
+        .prop_map(
+            |(hand_tiles, winning_tile, open_melds, closed_kans, tsumo)| UserInput {
+                hand_tiles,
+                winning_tile,
+                player_context: PlayerContext {
+                    is_menzen: open_melds.is_empty(),
+                    ..PlayerContext::default()
+                },
+                open_melds,
+                closed_kans,
+                game_context: GameContext::default(),
+                agari_type: if tsumo {
+                    AgariType::Tsumo
+                } else {
+                    AgariType::Ron
+                },
+            },
+        )
+}
+
+proptest! {
+    // arbitrary 13-18 tile hands, with and without melds, never panic
+    #[test]
+    fn organize_hand_never_panics(input in any_input()) {
+        let _ = organize_hand(&input);
+        let _ = calculate_agari(&input);
+    }
+
+    #[test]
+    fn organize_hand_never_panics_without_melds(
+        hand_tiles in prop::collection::vec(any_tile(), 13..=18),
+        winning_tile in any_tile(),
+    ) {
+        let input = UserInput {
+            hand_tiles,
+            winning_tile,
+            open_melds: Vec::new(),
+            closed_kans: Vec::new(),
+            player_context: PlayerContext::default(),
+            game_context: GameContext::default(),
+            agari_type: AgariType::Tsumo,
+        };
+        let _ = organize_hand(&input);
+        let _ = calculate_agari(&input);
+    }
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use common::{hand, south};
+use riichi_mahjong_scoring_calculator::implements::calculate_agari;
+use riichi_mahjong_scoring_calculator::implements::game::{AgariType, GameContext};
+use riichi_mahjong_scoring_calculator::implements::input::UserInput;
+use riichi_mahjong_scoring_calculator::implements::rules::{PlayMode, RuleConfig};
+use riichi_mahjong_scoring_calculator::implements::tiles::Kaze;
+use riichi_mahjong_scoring_calculator::implements::yaku::Yaku;
+
+// three players, two tsumo payers, no North seat and no 2-8m
+
+fn sanma(mut input: UserInput) -> UserInput {
+    input.game_context.rules.play_mode = PlayMode::Sanma;
+    input
+}
+
+#[test]
+fn dealer_tsumo_is_paid_by_two_players() {
+    let mut input = sanma(hand("123p456p789s11z999m", "3p", AgariType::Tsumo));
+    input.player_context.is_riichi = true;
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Riichi, Yaku::MenzenTsumo]);
+    assert_eq!(result.summary(), (2, 40, 2600));
+    assert_eq!(result.oya_payment, 1300);
+}
+
+#[test]
+fn non_dealer_tsumo_is_paid_by_two_players() {
+    let mut input = sanma(south(hand("123p456p789s11z999m", "3p", AgariType::Tsumo)));
+    input.player_context.is_riichi = true;
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.summary(), (2, 40, 2000));
+    assert_eq!((result.oya_payment, result.ko_payment), (1300, 700));
+}
+
+#[test]
+fn removed_manzu_is_rejected() {
+    let input = sanma(hand("123m456p789s11z999m", "3m", AgariType::Tsumo));
+    assert_eq!(
+        calculate_agari(&input).unwrap_err(),
+        "Manzu 2-8 are not used in sanma"
+    );
+}
+
+#[test]
+fn there_is_no_north_seat_or_round() {
+    let mut input = sanma(hand("123p456p789s11z999m", "3p", AgariType::Tsumo));
+    input.player_context.jikaze = Kaze::Pei;
+    input.player_context.is_oya = false;
+    assert_eq!(
+        calculate_agari(&input).unwrap_err(),
+        "There is no North seat or round in sanma"
+    );
+
+    let rules = RuleConfig {
+        play_mode: PlayMode::Sanma,
+        ..RuleConfig::default()
+    };
+    assert!(
+        GameContext::builder()
+            .round(Kaze::Pei)
+            .rules(rules)
+            .build()
+            .is_err()
+    );
+}
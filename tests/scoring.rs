@@ -0,0 +1,304 @@
+mod common;
+
+use common::{hand, south, tile, tiles, with_melds};
+use riichi_mahjong_scoring_calculator::implements::game::AgariType;
+use riichi_mahjong_scoring_calculator::implements::hand::Machi;
+use riichi_mahjong_scoring_calculator::implements::input::{OpenMeldInput, UserInput};
+use riichi_mahjong_scoring_calculator::implements::scoring::{HandLimit, score_diff};
+use riichi_mahjong_scoring_calculator::implements::tiles::tile_to_index;
+use riichi_mahjong_scoring_calculator::implements::yaku::Yaku;
+use riichi_mahjong_scoring_calculator::implements::{
+    NO_YAKU, NOT_A_WINNING_HAND, calculate_agari, dora_count, shanten,
+};
+
+// furiten is flagged on ron only
+
+#[test]
+fn ron_on_a_discarded_wait_is_furiten() {
+    let mut input = south(hand("123m456p789s11z999m", "9m", AgariType::Ron));
+    input.player_context.is_riichi = true;
+    input.player_context.discards = tiles("9m");
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Riichi]);
+    assert_eq!(result.summary(), (1, 40, 1300));
+    assert!(result.is_furiten);
+    assert!(result.to_string().contains("ron is not allowed"));
+}
+
+#[test]
+fn tsumo_on_a_discarded_wait_is_not_furiten() {
+    let mut input = south(hand("123m456p789s11z999m", "9m", AgariType::Tsumo));
+    input.player_context.is_riichi = true;
+    input.player_context.discards = tiles("9m");
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Riichi, Yaku::MenzenTsumo]);
+    assert_eq!(result.summary(), (2, 40, 2700));
+    assert!(!result.is_furiten);
+    assert!(!result.to_string().contains("Furiten"));
+}
+
+// a complete hand with only dora fails with NO_YAKU, dora_count says what it held
+
+#[test]
+fn open_middle_tiles_without_kuitan_is_no_yaku_with_two_dora() {
+    let mut input = with_melds(
+        south(hand("345p678s456s88p", "6s", AgariType::Ron)),
+        vec![OpenMeldInput::chi(tile("2m"))],
+    );
+    input.game_context.dora_indicators = tiles("7p");
+    input.game_context.rules.kuitan = false;
+    assert_eq!(calculate_agari(&input).unwrap_err(), NO_YAKU);
+    assert_eq!(dora_count(&input), Ok(2));
+
+    input.game_context.rules.kuitan = true;
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Tanyao, Yaku::Dora, Yaku::Dora]);
+    assert_eq!(result.summary(), (3, 30, 3900));
+}
+
+// a 1-shanten soup is NOT_A_WINNING_HAND, tile_counts holds what it was
+
+#[test]
+fn one_shanten_soup_is_not_a_winning_hand() {
+    let input = south(hand("123m456p789s9p11z3z5z", "9p", AgariType::Ron));
+    assert_eq!(calculate_agari(&input).unwrap_err(), NOT_A_WINNING_HAND);
+
+    let counts = input.tile_counts();
+    assert_eq!(counts.iter().map(|&n| n as usize).sum::<usize>(), 14);
+    assert_eq!(counts[tile_to_index(&tile("9p"))], 1);
+    assert_eq!(counts[tile_to_index(&tile("1z"))], 2);
+    assert_eq!(shanten(&tiles("123m456p789s9p11z3z5z")), Some(1));
+}
+
+// 5m completes 34m as a ryanmen and 46m as a kanchan, the pinfu reading wins
+
+#[test]
+fn ryanmen_reading_beats_kanchan() {
+    let input = south(hand("345456m234p678s88p", "5m", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(common::machi(&result), Machi::Ryanmen);
+    assert_eq!(result.yaku_list, vec![Yaku::Pinfu, Yaku::Tanyao]);
+    assert_eq!(result.summary(), (2, 30, 2000));
+}
+
+// all four tiles of a kan count for dora and aka dora
+
+#[test]
+fn closed_kan_of_dora_counts_four() {
+    let mut input = hand("123m456s789s99m", "9m", AgariType::Tsumo);
+    input.closed_kans = tiles("3p");
+    input.player_context.is_riichi = true;
+    input.game_context.dora_indicators = tiles("2p1z");
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.dora_breakdown.dora, 4);
+    assert_eq!(
+        result.yaku_list,
+        vec![
+            Yaku::Riichi,
+            Yaku::MenzenTsumo,
+            Yaku::Dora,
+            Yaku::Dora,
+            Yaku::Dora,
+            Yaku::Dora
+        ]
+    );
+    assert_eq!(result.summary(), (6, 40, 18000));
+}
+
+#[test]
+fn red_five_in_a_kan_is_one_aka() {
+    let mut input = hand("123m456s789s99m", "9m", AgariType::Tsumo);
+    input.closed_kans = tiles("5p");
+    input.player_context.is_riichi = true;
+    input.game_context.num_akadora = 1;
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.dora_breakdown.aka, 1);
+    assert_eq!(
+        result.yaku_list,
+        vec![Yaku::Riichi, Yaku::MenzenTsumo, Yaku::AkaDora]
+    );
+    assert_eq!(result.summary(), (3, 40, 7800));
+}
+
+// Chiitoitsu is a flat 25 fu
+
+#[test]
+fn chiitoitsu_ron_is_25_fu() {
+    let input = south(hand("1199m2255p3388s11z", "1z", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Chiitoitsu]);
+    assert_eq!(result.summary(), (2, 25, 1600));
+}
+
+#[test]
+fn riichi_chiitoitsu_tsumo_is_4_han_25_fu() {
+    let mut input = south(hand("1199m2255p3388s11z", "1z", AgariType::Tsumo));
+    input.player_context.is_riichi = true;
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(
+        result.yaku_list,
+        vec![Yaku::Chiitoitsu, Yaku::Riichi, Yaku::MenzenTsumo]
+    );
+    assert_eq!(result.summary(), (4, 25, 6400));
+    assert_eq!((result.oya_payment, result.ko_payment), (3200, 1600));
+}
+
+#[test]
+fn ryanpeikou_reading_beats_chiitoitsu() {
+    let input = south(hand("223344m556677p99s", "2m", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Pinfu, Yaku::Ryanpeikou]);
+    assert_eq!(result.summary(), (4, 30, 7700));
+}
+
+// four of a kind is two Chiitoitsu pairs only under chiitoitsu_allow_four
+
+fn four_fives_pairs() -> UserInput {
+    hand("5555m22p66p99s11z77z", "7z", AgariType::Ron)
+}
+
+#[test]
+fn chiitoitsu_rejects_four_of_a_kind_by_default() {
+    assert_eq!(
+        calculate_agari(&four_fives_pairs()).unwrap_err(),
+        NOT_A_WINNING_HAND
+    );
+}
+
+#[test]
+fn chiitoitsu_with_allow_four() {
+    let mut input = four_fives_pairs();
+    input.game_context.rules.chiitoitsu_allow_four = true;
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Chiitoitsu]);
+    assert_eq!(result.summary(), (2, 25, 2400));
+}
+
+// 4 han 30 fu rounds up to Mangan only under kiriage_mangan
+
+fn four_han_thirty_fu() -> UserInput {
+    let mut input = south(hand("234234m567p678s55s", "6s", AgariType::Ron));
+    input.player_context.is_riichi = true;
+    input
+}
+
+#[test]
+fn four_han_thirty_fu_without_kiriage() {
+    let result = calculate_agari(&four_han_thirty_fu()).unwrap();
+    assert_eq!(
+        result.yaku_list,
+        vec![Yaku::Riichi, Yaku::Pinfu, Yaku::Tanyao, Yaku::Iipeikou]
+    );
+    assert_eq!(result.limit_name, None);
+    assert_eq!(result.summary(), (4, 30, 7700));
+}
+
+#[test]
+fn four_han_thirty_fu_with_kiriage() {
+    let mut input = four_han_thirty_fu();
+    input.game_context.rules.kiriage_mangan = true;
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.limit_name, Some(HandLimit::Mangan));
+    assert_eq!(result.summary(), (4, 30, 8000));
+}
+
+// 13 han of regular yaku is Kazoe Yakuman, a double yakuman pays double
+
+#[test]
+fn thirteen_han_is_kazoe_yakuman() {
+    let mut input = south(hand("234234m567m678m99m", "6m", AgariType::Tsumo));
+    input.player_context.is_riichi = true;
+    input.game_context.dora_indicators = tiles("3m");
+    input.game_context.num_akadora = 1;
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(
+        result.yaku_list,
+        vec![
+            Yaku::Riichi,
+            Yaku::MenzenTsumo,
+            Yaku::Pinfu,
+            Yaku::Iipeikou,
+            Yaku::Chinitsu,
+            Yaku::Dora,
+            Yaku::Dora,
+            Yaku::AkaDora
+        ]
+    );
+    assert_eq!(result.limit_name, Some(HandLimit::KazoeYakuman));
+    assert_eq!(result.limit_label().as_deref(), Some("Kazoe Yakuman"));
+    assert_eq!(result.summary(), (13, 20, 32000));
+}
+
+#[test]
+fn suuankou_tanki_is_a_double_yakuman() {
+    let input = south(hand("111m444p777s999m55z", "5z", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::SuuankouTanki]);
+    assert_eq!(result.limit_name, Some(HandLimit::Yakuman));
+    assert_eq!(result.limit_label().as_deref(), Some("Yakuman x2"));
+    assert_eq!(result.summary(), (26, 0, 64000));
+}
+
+// score_diff lists every field that differs
+
+#[test]
+fn score_diff_between_tsumo_and_ron() {
+    let mut input = south(hand("234m567p678s345s44z", "3s", AgariType::Ron));
+    input.player_context.is_riichi = true;
+    let ron = calculate_agari(&input).unwrap();
+    input.agari_type = AgariType::Tsumo;
+    input.hand_tiles.push(input.winning_tile);
+    let tsumo = calculate_agari(&input).unwrap();
+
+    assert_eq!(score_diff(&ron, &ron), Vec::<String>::new());
+    assert_eq!(
+        score_diff(&ron, &tsumo),
+        vec![
+            "han: 2 vs 3",
+            "fu: 30 vs 20",
+            "oya_payment: 0 vs 1300",
+            "ko_payment: 0 vs 700",
+            "total_payment: 2000 vs 2700",
+            "MenzenTsumo: x0 vs x1",
+        ]
+    );
+}
+
+// Riichi alone carries the dora
+
+#[test]
+fn riichi_only_hand_with_three_dora() {
+    let mut input = south(hand("123m456p789s234s99m", "2m", AgariType::Ron));
+    input.player_context.is_riichi = true;
+    input.game_context.dora_indicators = tiles("8m");
+    input.game_context.uradora_indicators = tiles("8s");
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(
+        result.yaku_list,
+        vec![Yaku::Riichi, Yaku::Dora, Yaku::Dora, Yaku::UraDora]
+    );
+    assert_eq!(result.summary(), (4, 40, 8000));
+}
+
+// a 5m indicator points at 6m whether or not the 5m in hand is red; the red
+// five is counted from num_akadora on top of the plain dora match
+
+#[test]
+fn red_five_counts_on_top_of_the_indicator_match() {
+    let mut input = south(hand("345m678m456p789s22p", "3m", AgariType::Ron));
+    input.player_context.is_riichi = true;
+    input.game_context.dora_indicators = tiles("5m");
+    let plain = calculate_agari(&input).unwrap();
+
+    input.game_context.num_akadora = 1;
+    let red = calculate_agari(&input).unwrap();
+    assert_eq!(red.dora_breakdown.dora, plain.dora_breakdown.dora);
+    assert_eq!(red.dora_breakdown.dora, 1);
+    assert_eq!(red.dora_breakdown.aka, 1);
+    assert_eq!(
+        red.yaku_list,
+        vec![Yaku::Riichi, Yaku::Pinfu, Yaku::Dora, Yaku::AkaDora]
+    );
+    assert_eq!(plain.summary(), (3, 30, 3900));
+    assert_eq!(red.summary(), (4, 30, 7700));
+}
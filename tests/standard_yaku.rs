@@ -0,0 +1,425 @@
+mod common;
+
+use common::{hand, south, tile, with_melds};
+use riichi_mahjong_scoring_calculator::implements::fu::fu_breakdown;
+use riichi_mahjong_scoring_calculator::implements::game::AgariType;
+use riichi_mahjong_scoring_calculator::implements::hand::KanType;
+use riichi_mahjong_scoring_calculator::implements::input::{OpenMeldInput, UserInput};
+use riichi_mahjong_scoring_calculator::implements::scoring::HandLimit;
+use riichi_mahjong_scoring_calculator::implements::yaku::Yaku;
+use riichi_mahjong_scoring_calculator::implements::{NO_YAKU, calculate_agari};
+
+// open Tanyao follows RuleConfig::kuitan
+
+fn open_all_simples() -> UserInput {
+    with_melds(
+        south(hand("345p678s456s88p", "6s", AgariType::Ron)),
+        vec![OpenMeldInput::chi(tile("2m"))],
+    )
+}
+
+#[test]
+fn open_tanyao_with_kuitan() {
+    let result = calculate_agari(&open_all_simples()).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Tanyao]);
+    assert_eq!(result.summary(), (1, 30, 1000));
+}
+
+#[test]
+fn open_tanyao_without_kuitan_is_no_yaku() {
+    let mut input = open_all_simples();
+    input.game_context.rules.kuitan = false;
+    assert_eq!(calculate_agari(&input).unwrap_err(), NO_YAKU);
+}
+
+#[test]
+fn closed_tanyao_without_kuitan() {
+    let mut input = south(hand("234m345p678s456s88p", "6s", AgariType::Ron));
+    input.game_context.rules.kuitan = false;
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Pinfu, Yaku::Tanyao]);
+    assert_eq!(result.summary(), (2, 30, 2000));
+}
+
+// a kan elsewhere in the hand doesn't hide the straight
+
+#[test]
+fn ittsu_next_to_a_closed_kan() {
+    let mut input = south(hand("123456789m99s", "6m", AgariType::Ron));
+    input.closed_kans = vec![tile("5p")];
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Ittsu]);
+    assert_eq!(result.summary(), (2, 50, 3200));
+}
+
+// the stronger of two exclusive yaku is the only one awarded
+
+#[test]
+fn junchan_without_chanta() {
+    let input = south(hand("123789m123p99p789s", "3m", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Junchan]);
+    assert_eq!(result.summary(), (3, 40, 5200));
+}
+
+#[test]
+fn chinitsu_without_honitsu() {
+    let input = south(hand("12334556789995m", "5m", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Chinitsu]);
+    assert_eq!(result.summary(), (6, 40, 12000));
+}
+
+// a terminal kan keeps Chanta like a terminal triplet
+// (789m instead of the request's 123m, which would need a fifth 1m)
+
+#[test]
+fn chanta_with_a_closed_terminal_kan() {
+    let mut input = south(hand("789m789p111z99s", "7m", AgariType::Ron));
+    input.closed_kans = vec![tile("1m")];
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::YakuhaiBakaze, Yaku::Chanta]);
+    assert_eq!(result.summary(), (3, 80, 8000));
+}
+
+#[test]
+fn chanta_with_an_open_terminal_kan() {
+    let input = with_melds(
+        south(hand("789m789p111z99s", "7m", AgariType::Ron)),
+        vec![OpenMeldInput::kan(tile("1m"), KanType::Daiminkan)],
+    );
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::YakuhaiBakaze, Yaku::Chanta]);
+    assert_eq!(result.summary(), (2, 50, 3200));
+}
+
+// Ittsu and Sanshoku are awarded independently; one hand can't hold
+// both, as that would take five sequences
+
+#[test]
+fn sanshoku_without_ittsu() {
+    let input = south(hand("123456m123p123s99s", "6m", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Pinfu, Yaku::SanshokuDoujun]);
+    assert_eq!(result.summary(), (3, 30, 3900));
+}
+
+#[test]
+fn ittsu_without_sanshoku() {
+    let input = south(hand("123456789m234p99s", "2p", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Pinfu, Yaku::Ittsu]);
+    assert_eq!(result.summary(), (3, 30, 3900));
+}
+
+// Ippatsu and Menzen Tsumo are separate han
+
+#[test]
+fn riichi_ippatsu_tsumo_pinfu() {
+    let mut input = south(hand("234m567p345s678s99m", "2m", AgariType::Tsumo));
+    input.player_context.is_riichi = true;
+    input.player_context.is_ippatsu = true;
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(
+        result.yaku_list,
+        vec![Yaku::Riichi, Yaku::Ippatsu, Yaku::MenzenTsumo, Yaku::Pinfu]
+    );
+    assert_eq!(result.summary(), (4, 20, 5200));
+}
+
+#[test]
+fn ippatsu_ron_has_no_menzen_tsumo() {
+    let mut input = south(hand("234m567p345s678s99m", "2m", AgariType::Ron));
+    input.player_context.is_riichi = true;
+    input.player_context.is_ippatsu = true;
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(
+        result.yaku_list,
+        vec![Yaku::Riichi, Yaku::Ippatsu, Yaku::Pinfu]
+    );
+    assert_eq!(result.summary(), (3, 30, 3900));
+}
+
+// the dragon pair gives Shousangen but no Yakuhai of its own
+
+#[test]
+fn toitoi_honitsu_shousangen() {
+    let input = with_melds(
+        south(hand("222444m666z77z", "4m", AgariType::Ron)),
+        vec![OpenMeldInput::pon(tile("5z"))],
+    );
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(
+        result.yaku_list,
+        vec![
+            Yaku::YakuhaiSangenpai,
+            Yaku::YakuhaiSangenpai,
+            Yaku::Toitoi,
+            Yaku::Shousangen,
+            Yaku::Honitsu
+        ]
+    );
+    // 2 + 2 + 2 + 2 (open Honitsu)
+    assert_eq!(result.summary(), (8, 40, 16000));
+}
+
+// an East triplet in East round from the East seat is two yakuhai
+
+#[test]
+fn double_east_triplet_is_two_han() {
+    let result = calculate_agari(&hand("111z234m567p678s55p", "6s", AgariType::Ron)).unwrap();
+    assert_eq!(
+        result.yaku_list,
+        vec![Yaku::YakuhaiBakaze, Yaku::YakuhaiJikaze]
+    );
+    assert_eq!(result.summary(), (2, 40, 3900));
+}
+
+// Sanshoku Doukou counts numbered triplets only
+
+#[test]
+fn three_dragon_triplets_are_not_sanshoku_doukou() {
+    let input = south(hand("555z666z777z234m55p", "4m", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Daisangen]);
+    assert_eq!(result.summary(), (13, 0, 32000));
+}
+
+#[test]
+fn threes_in_every_suit_are_sanshoku_doukou() {
+    let input = south(hand("333m333p333s456m99s", "5m", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Sanankou, Yaku::SanshokuDoukou]);
+    assert_eq!(result.summary(), (4, 50, 8000));
+}
+
+// the pair has to touch a terminal or honor for Chanta too
+
+fn riichi(mut input: UserInput) -> UserInput {
+    input.player_context.is_riichi = true;
+    input
+}
+
+#[test]
+fn chanta_with_a_terminal_pair() {
+    let input = riichi(south(hand("123m789p123s444z99m", "3m", AgariType::Ron)));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Riichi, Yaku::Chanta]);
+    assert_eq!(result.summary(), (3, 40, 5200));
+}
+
+#[test]
+fn simple_pair_breaks_chanta() {
+    let input = riichi(south(hand("123m789p123s444z55p", "3m", AgariType::Ron)));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Riichi]);
+    assert_eq!(result.summary(), (1, 40, 1300));
+}
+
+// Pinfu and Iipeikou read the same sequences, 5 han 20 fu is Mangan
+
+#[test]
+fn riichi_tsumo_pinfu_tanyao_iipeikou() {
+    let input = riichi(south(hand("234234m567p678s55s", "6s", AgariType::Tsumo)));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(
+        result.yaku_list,
+        vec![
+            Yaku::Riichi,
+            Yaku::MenzenTsumo,
+            Yaku::Pinfu,
+            Yaku::Tanyao,
+            Yaku::Iipeikou
+        ]
+    );
+    assert_eq!(result.limit_name, Some(HandLimit::Mangan));
+    assert_eq!(result.summary(), (5, 20, 8000));
+}
+
+// all yaochuu tiles make Honroutou, never Chanta
+
+#[test]
+fn honroutou_toitoi_honitsu_without_chanta() {
+    let input = with_melds(
+        south(hand("999m555z333z11m", "9m", AgariType::Ron)),
+        vec![OpenMeldInput::pon(tile("4z"))],
+    );
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(
+        result.yaku_list,
+        vec![
+            Yaku::YakuhaiSangenpai,
+            Yaku::Toitoi,
+            Yaku::Honroutou,
+            Yaku::Honitsu
+        ]
+    );
+    assert_eq!(result.summary(), (7, 50, 12000));
+}
+
+// Honroutou needs an honor, all terminals is Chinroutou instead
+
+#[test]
+fn honroutou_toitoi_across_suits() {
+    let input = with_melds(
+        south(hand("999p111s333z99s", "3z", AgariType::Ron)),
+        vec![OpenMeldInput::pon(tile("1m"))],
+    );
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Toitoi, Yaku::Honroutou]);
+    assert_eq!(result.summary(), (4, 50, 8000));
+}
+
+#[test]
+fn all_terminals_is_chinroutou_not_honroutou() {
+    let input = with_melds(
+        south(hand("999p111s999m99s", "9m", AgariType::Ron)),
+        vec![OpenMeldInput::pon(tile("1m"))],
+    );
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Chinroutou]);
+    assert_eq!(result.summary(), (13, 0, 32000));
+}
+
+// an open pinfu shape is 30 fu on ron and on tsumo
+
+#[test]
+fn open_pinfu_shape_ron_is_thirty_fu() {
+    let input = open_all_simples();
+    let result = calculate_agari(&input).unwrap();
+    let items = fu_breakdown(
+        &result.hand_structure,
+        &result.yaku_list,
+        &input.player_context,
+        &input.game_context,
+        AgariType::Ron,
+    );
+    assert_eq!(items, vec![("Base", 20), ("Open pinfu shape", 10)]);
+    assert_eq!(result.summary(), (1, 30, 1000));
+}
+
+#[test]
+fn open_pinfu_shape_tsumo_is_thirty_fu() {
+    let mut input = open_all_simples();
+    input.agari_type = AgariType::Tsumo;
+    input.hand_tiles.push(input.winning_tile);
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Tanyao]);
+    assert_eq!(result.summary(), (1, 30, 1100));
+}
+
+// a guest wind pair keeps Pinfu unless pinfu_guest_wind_ok is off
+
+#[test]
+fn guest_wind_pair_keeps_pinfu() {
+    let input = riichi(south(hand("234m567p678s345s44z", "3s", AgariType::Ron)));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Riichi, Yaku::Pinfu]);
+    assert_eq!(result.summary(), (2, 30, 2000));
+}
+
+#[test]
+fn round_wind_pair_breaks_pinfu() {
+    let input = riichi(south(hand("234m567p678s345s11z", "3s", AgariType::Ron)));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Riichi]);
+    assert_eq!(result.summary(), (1, 40, 1300));
+}
+
+#[test]
+fn guest_wind_pair_without_the_rule() {
+    let mut input = riichi(south(hand("234m567p678s345s44z", "3s", AgariType::Ron)));
+    input.game_context.rules.pinfu_guest_wind_ok = false;
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Riichi]);
+    assert_eq!(result.summary(), (1, 30, 1000));
+}
+
+// two identical sequences are Iipeikou only when closed
+
+#[test]
+fn two_identical_chis_are_not_iipeikou() {
+    let input = with_melds(
+        south(hand("567p678s55s", "6s", AgariType::Ron)),
+        vec![
+            OpenMeldInput::chi(tile("2m")),
+            OpenMeldInput::chi(tile("2m")),
+        ],
+    );
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Tanyao]);
+    assert_eq!(result.summary(), (1, 30, 1000));
+}
+
+#[test]
+fn two_identical_closed_sequences_are_iipeikou() {
+    let input = south(hand("234234m567p678s55s", "6s", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(
+        result.yaku_list,
+        vec![Yaku::Pinfu, Yaku::Tanyao, Yaku::Iipeikou]
+    );
+    assert_eq!(result.summary(), (3, 30, 3900));
+}
+
+// a triplet completed by tsumo stays concealed
+
+#[test]
+fn three_closed_triplets_on_a_shanpon_tsumo() {
+    let input = south(hand("111m444p777s234m99s", "7s", AgariType::Tsumo));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::MenzenTsumo, Yaku::Sanankou]);
+    assert_eq!(result.summary(), (3, 40, 5200));
+}
+
+#[test]
+fn three_closed_triplets_on_a_ryanmen_tsumo() {
+    let input = south(hand("111m444p777s234m99s", "4m", AgariType::Tsumo));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::MenzenTsumo, Yaku::Sanankou]);
+    assert_eq!(result.summary(), (3, 40, 5200));
+}
+
+#[test]
+fn four_closed_triplets_on_tsumo_is_suuankou() {
+    let input = south(hand("111m444p777s999s55p", "9s", AgariType::Tsumo));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Suuankou]);
+    assert_eq!(result.summary(), (13, 0, 32000));
+}
+
+#[test]
+fn ron_on_a_sequence_keeps_the_matching_triplet_concealed() {
+    // 5m completes 34m, so 555m is still a concealed triplet
+    let input = south(hand("222p777s99s345m555m", "5m", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Sanankou]);
+    assert_eq!(result.summary(), (2, 50, 3200));
+}
+
+#[test]
+fn shanpon_ron_triplet_scores_as_open_next_to_toitoi() {
+    let input = south(hand("111m444p777s999m55z", "9m", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Toitoi, Yaku::Sanankou]);
+    let items = fu_breakdown(
+        &result.hand_structure,
+        &result.yaku_list,
+        &input.player_context,
+        &input.game_context,
+        AgariType::Ron,
+    );
+    assert_eq!(
+        items,
+        vec![
+            ("Base", 20),
+            ("Menzen ron", 10),
+            ("Concealed terminal/honor triplet", 8),
+            ("Open terminal/honor triplet", 4),
+            ("Concealed simple triplet", 4),
+            ("Concealed simple triplet", 4),
+            ("Yakuhai pair", 2),
+        ]
+    );
+    assert_eq!(result.summary(), (4, 60, 8000));
+}
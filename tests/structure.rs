@@ -0,0 +1,173 @@
+mod common;
+
+use common::tiles;
+use riichi_mahjong_scoring_calculator::implements::game::{AgariType, GameContext, PlayerContext};
+use riichi_mahjong_scoring_calculator::implements::hand::{HandOrganization, HandStructure, Machi};
+use riichi_mahjong_scoring_calculator::implements::input::{OpenMeldInput, UserInput};
+use riichi_mahjong_scoring_calculator::implements::tiles::{Hai, sort_tiles};
+use riichi_mahjong_scoring_calculator::implements::yaku::Yaku;
+use riichi_mahjong_scoring_calculator::implements::{
+    calculate_agari, check_all_yaku, organize_hand, organize_hand_split, ukeire,
+};
+
+// irregular counts and the resolved structure's tiles agree
+
+fn round_trip(notation: &str, win: &str) -> (Vec<Hai>, HandStructure) {
+    let mut hand = tiles(notation);
+    let organization = HandOrganization::irregular_from_tiles(&hand, common::tile(win));
+    let result = check_all_yaku(
+        organization,
+        &PlayerContext::default(),
+        &GameContext::default(),
+        AgariType::Tsumo,
+    )
+    .unwrap();
+    sort_tiles(&mut hand);
+    (hand, result.hand_structure)
+}
+
+#[test]
+fn chiitoitsu_structure_holds_the_input_tiles() {
+    let (hand, structure) = round_trip("1199m2255p3388s11z", "1z");
+    assert!(matches!(structure, HandStructure::Chiitoitsu { .. }));
+    let mut back = structure.tiles();
+    sort_tiles(&mut back);
+    assert_eq!(back.len(), 14);
+    assert_eq!(back, hand);
+}
+
+#[test]
+fn kokushi_structure_holds_the_input_tiles() {
+    let (hand, structure) = round_trip("19m19p19s12345677z", "7z");
+    assert!(matches!(structure, HandStructure::KokushiMusou { .. }));
+    let mut back = structure.tiles();
+    sort_tiles(&mut back);
+    assert_eq!(back.len(), 14);
+    assert_eq!(back, hand);
+}
+
+// 13 tiles + winning tile gives the same readings as the UserInput API
+
+#[test]
+fn split_entry_point_matches_organize_hand() {
+    let input = common::south(common::hand("345456m234p678s88p", "5m", AgariType::Ron));
+    let from_input = organize_hand(&input).unwrap();
+    let from_split = organize_hand_split(&input.hand_tiles, input.winning_tile, &[]).unwrap();
+    assert_eq!(from_input, from_split);
+    // one reading per wait, plus the irregular counts for Chiitoitsu/Kokushi
+    let waits: Vec<Machi> = from_split
+        .iter()
+        .filter_map(|o| match o {
+            HandOrganization::YonmentsuIchiatama(hand) => Some(hand.machi),
+            HandOrganization::Irregular { .. } => None,
+        })
+        .collect();
+    assert_eq!(waits, vec![Machi::Ryanmen, Machi::Kanchan]);
+    assert_eq!(from_split.len(), 3);
+}
+
+// an open hand never keeps a menzen-only yaku
+
+#[test]
+fn open_flag_strips_menzen_only_yaku() {
+    let input = common::hand("234234m567p678s55s", "6s", AgariType::Ron);
+    let organization = organize_hand(&input)
+        .unwrap()
+        .into_iter()
+        .find(|o| matches!(o, HandOrganization::YonmentsuIchiatama(h) if h.machi == Machi::Ryanmen))
+        .unwrap();
+    let mut player = PlayerContext {
+        is_riichi: true,
+        ..Default::default()
+    };
+    let game = GameContext::default();
+
+    let closed = check_all_yaku(organization.clone(), &player, &game, AgariType::Ron).unwrap();
+    assert_eq!(
+        closed.yaku_list,
+        vec![Yaku::Riichi, Yaku::Pinfu, Yaku::Tanyao, Yaku::Iipeikou]
+    );
+
+    player.is_menzen = false;
+    let open = check_all_yaku(organization, &player, &game, AgariType::Ron).unwrap();
+    assert_eq!(open.yaku_list, vec![Yaku::Tanyao]);
+}
+
+// HandStructure::tiles holds 14 tiles, plus one per kan
+
+fn scored_structure(input: &UserInput) -> HandStructure {
+    calculate_agari(input).unwrap().hand_structure
+}
+
+fn fourteen_tile_structure(notation: &str, win: &str) -> HandStructure {
+    let structure = scored_structure(&common::hand(notation, win, AgariType::Tsumo));
+    assert_eq!(structure.tiles().len(), 14, "{notation}");
+    structure
+}
+
+#[test]
+fn every_structure_returns_fourteen_tiles() {
+    assert!(matches!(
+        fourteen_tile_structure("123m456p789s11z999m", "3m"),
+        HandStructure::YonmentsuIchiatama(_)
+    ));
+    assert!(matches!(
+        fourteen_tile_structure("1199m2255p3388s11z", "1z"),
+        HandStructure::Chiitoitsu { .. }
+    ));
+    assert!(matches!(
+        fourteen_tile_structure("19m19p19s12345677z", "7z"),
+        HandStructure::KokushiMusou { .. }
+    ));
+    assert!(matches!(
+        fourteen_tile_structure("11123455678999m", "5m"),
+        HandStructure::ChuurenPoutou { .. }
+    ));
+}
+
+#[test]
+fn kans_add_a_tile_each() {
+    let mut input = common::hand("456p789s11z999m", "9m", AgariType::Tsumo);
+    input.closed_kans = tiles("2m");
+    input.game_context.dora_indicators = tiles("1z2z");
+    assert_eq!(scored_structure(&input).tiles().len(), 15);
+}
+
+// ukeire counts the unseen copies of each wait
+
+#[test]
+fn ryanmen_ukeire_is_eight_less_what_is_seen() {
+    let hand = tiles("123m456p789s34s99s");
+    let two_s = common::tile("2s");
+    let five_s = common::tile("5s");
+    assert_eq!(ukeire(&hand, &[]), vec![(two_s, 4), (five_s, 4)]);
+    assert_eq!(ukeire(&hand, &tiles("55s")), vec![(two_s, 4), (five_s, 2)]);
+    assert_eq!(
+        ukeire(&hand, &tiles("2222s")),
+        vec![(two_s, 0), (five_s, 4)]
+    );
+    // a held 2s is one less to draw
+    assert_eq!(
+        ukeire(&tiles("123m456p234s34s99s"), &[]),
+        vec![(two_s, 3), (five_s, 4)]
+    );
+}
+
+// 裸単騎: four pons leave the winning tile alone as the pair wait
+
+#[test]
+fn hadaka_tanki_after_four_pons() {
+    let input = common::with_melds(
+        common::south(common::hand("55p", "5p", AgariType::Ron)),
+        vec![
+            OpenMeldInput::pon(common::tile("2m")),
+            OpenMeldInput::pon(common::tile("4s")),
+            OpenMeldInput::pon(common::tile("6p")),
+            OpenMeldInput::pon(common::tile("1z")),
+        ],
+    );
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(common::machi(&result), Machi::Tanki);
+    assert_eq!(result.yaku_list, vec![Yaku::YakuhaiBakaze, Yaku::Toitoi]);
+    assert_eq!(result.summary(), (3, 40, 5200));
+}
@@ -0,0 +1,229 @@
+#![cfg(feature = "tenhou")]
+
+mod common;
+
+use common::{tile, tiles};
+use riichi_mahjong_scoring_calculator::implements::calculate_agari;
+use riichi_mahjong_scoring_calculator::implements::game::AgariType;
+use riichi_mahjong_scoring_calculator::implements::hand::KanType;
+use riichi_mahjong_scoring_calculator::implements::input::OpenMeldInput;
+use riichi_mahjong_scoring_calculator::implements::tenhou::from_tenhou_agari;
+use riichi_mahjong_scoring_calculator::implements::tiles::Kaze;
+use riichi_mahjong_scoring_calculator::implements::yaku::Yaku;
+use serde_json::{Value, json};
+
+// one East 1 round: [kyoku, honba, sticks], scores, dora, ura, then haipai,
+// takes and discards per seat, then the result
+fn round(dora: Value, ura: Value, seats: [[Value; 3]; 4], result: Value) -> Value {
+    let mut round = vec![json!([0, 0, 0]), json!([25000, 25000, 25000, 25000])];
+    round.extend([dora, ura]);
+    round.extend(seats.into_iter().flatten());
+    round.push(result);
+    Value::Array(round)
+}
+
+fn idle() -> [Value; 3] {
+    [json!([]), json!([]), json!([])]
+}
+
+// the dealer waits on East alone in riichi and player 1 throws the drawn East
+fn dealer_ron(result: Value) -> Value {
+    round(
+        json!([42]),
+        json!([44]),
+        [
+            [
+                json!([11, 12, 13, 24, 25, 26, 37, 38, 39, 19, 19, 19, 41]),
+                json!([21]),
+                json!(["r60"]),
+            ],
+            [json!([]), json!([41]), json!([60])],
+            idle(),
+            idle(),
+        ],
+        result,
+    )
+}
+
+#[test]
+fn dealer_ron_in_a_full_log() {
+    let result = json!([
+        "和了",
+        [9600, -9600, 0, 0],
+        [0, 1, 0, "50符3飜9600点", "立直(1飜)", "裏ドラ(2飜)"]
+    ]);
+    let log = json!({ "log": [dealer_ron(result)] });
+    let input = from_tenhou_agari(&log.to_string()).unwrap();
+
+    assert_eq!(input.agari_type, AgariType::Ron);
+    assert_eq!(input.hand_tiles, tiles("123999m456p789s1z"));
+    assert_eq!(input.winning_tile, tile("1z"));
+    assert!(input.player_context.is_oya && input.player_context.is_riichi);
+    assert_eq!(input.player_context.discards, tiles("1p"));
+    assert_eq!(input.game_context.dora_indicators, tiles("2z"));
+    assert_eq!(input.game_context.uradora_indicators, tiles("4z"));
+
+    let scored = calculate_agari(&input).unwrap();
+    assert_eq!(
+        scored.yaku_list,
+        vec![Yaku::Riichi, Yaku::UraDora, Yaku::UraDora]
+    );
+    assert_eq!(scored.summary(), (3, 50, 9600));
+}
+
+#[test]
+fn double_ron_takes_the_first_winner() {
+    let result = json!([
+        "和了",
+        [9600, -9600, 0, 0],
+        [0, 1, 0, "50符3飜9600点", "立直(1飜)", "裏ドラ(2飜)"],
+        [0, -1000, 1000, 0],
+        [2, 1, 2, "30符1飜1000点", "役牌 中(1飜)"]
+    ]);
+    let input = from_tenhou_agari(&dealer_ron(result).to_string()).unwrap();
+    assert!(input.player_context.is_oya);
+    assert_eq!(input.player_context.jikaze, Kaze::Ton);
+    assert_eq!(input.winning_tile, tile("1z"));
+}
+
+#[test]
+fn tsumo_after_a_pon_from_the_left() {
+    let log = round(
+        json!([31]),
+        json!([]),
+        [
+            idle(),
+            [
+                json!([47, 47, 12, 13, 14, 25, 26, 27, 33, 34, 35, 38, 19]),
+                json!(["p474747", 38]),
+                json!([19]),
+            ],
+            idle(),
+            idle(),
+        ],
+        json!([
+            "和了",
+            [-500, 1100, -300, -300],
+            [1, 1, 1, "30符1飜1100点", "役牌 中(1飜)"]
+        ]),
+    );
+    let input = from_tenhou_agari(&log.to_string()).unwrap();
+
+    assert_eq!(input.agari_type, AgariType::Tsumo);
+    assert_eq!(input.hand_tiles, tiles("234m567p34588s"));
+    assert_eq!(input.winning_tile, tile("8s"));
+    assert_eq!(
+        input.open_melds,
+        vec![OpenMeldInput::pon(tile("7z")).called(tile("7z"), Kaze::Ton)]
+    );
+    assert!(!input.player_context.is_menzen);
+
+    let scored = calculate_agari(&input).unwrap();
+    assert_eq!(scored.yaku_list, vec![Yaku::YakuhaiSangenpai]);
+    assert_eq!(scored.summary(), (1, 30, 1100));
+}
+
+#[test]
+fn chi_with_a_red_five_and_a_daiminkan_rinshan() {
+    let log = round(
+        json!([41, 42]),
+        json!([]),
+        [
+            idle(),
+            idle(),
+            [
+                json!([52, 26, 39, 39, 39, 11, 12, 13, 44, 44, 31, 32, 19]),
+                json!(["c275226", "39m393939", 33]),
+                json!([19, 0]),
+            ],
+            idle(),
+        ],
+        json!([
+            "和了",
+            [-1300, -700, 2700, -700],
+            [2, 2, 2, "40符2飜2700点", "嶺上開花(1飜)", "赤ドラ(1飜)"]
+        ]),
+    );
+    let input = from_tenhou_agari(&log.to_string()).unwrap();
+
+    assert_eq!(input.hand_tiles, tiles("123m123s44z"));
+    assert_eq!(input.winning_tile, tile("3s"));
+    assert_eq!(
+        input.open_melds,
+        vec![
+            OpenMeldInput::chi(tile("5p")).called(tile("7p"), Kaze::Nan),
+            OpenMeldInput::kan(tile("9s"), KanType::Daiminkan).called(tile("9s"), Kaze::Ton),
+        ]
+    );
+    assert_eq!(input.game_context.num_akadora, 1);
+    assert!(input.game_context.is_rinshan);
+
+    let scored = calculate_agari(&input).unwrap();
+    assert_eq!(scored.yaku_list, vec![Yaku::RinshanKaihou, Yaku::AkaDora]);
+    assert_eq!(scored.summary(), (2, 40, 2700));
+}
+
+#[test]
+fn added_kan_and_closed_kan() {
+    let log = round(
+        json!([31, 32, 33]),
+        json!([]),
+        [
+            idle(),
+            idle(),
+            idle(),
+            [
+                json!([41, 41, 41, 41, 45, 45, 11, 12, 13, 21, 22, 23, 19]),
+                json!(["p454545", 45, 36, 36]),
+                json!([19, "k45454545", "414141a41"]),
+            ],
+        ],
+        json!([
+            "和了",
+            [-4000, -2000, -2000, 8000],
+            [
+                3,
+                3,
+                3,
+                "80符3飜8000点",
+                "嶺上開花(1飜)",
+                "場風 東(1飜)",
+                "役牌 白(1飜)"
+            ]
+        ]),
+    );
+    let input = from_tenhou_agari(&log.to_string()).unwrap();
+
+    assert_eq!(
+        input.open_melds,
+        vec![OpenMeldInput::kan(tile("5z"), KanType::Shouminkan).called(tile("5z"), Kaze::Shaa)]
+    );
+    assert_eq!(input.closed_kans, tiles("1z"));
+    assert_eq!(input.hand_tiles, tiles("123m123p66s"));
+
+    let scored = calculate_agari(&input).unwrap();
+    assert_eq!(
+        scored.yaku_list,
+        vec![
+            Yaku::RinshanKaihou,
+            Yaku::YakuhaiSangenpai,
+            Yaku::YakuhaiBakaze
+        ]
+    );
+    assert_eq!(scored.summary(), (3, 80, 8000));
+}
+
+#[test]
+fn discarding_an_unheld_tile_is_an_error() {
+    let result = json!([
+        "和了",
+        [9600, -9600, 0, 0],
+        [0, 1, 0, "50符3飜9600点", "立直(1飜)"]
+    ]);
+    let mut log = dealer_ron(result);
+    log[6] = json!([23]);
+    assert_eq!(
+        from_tenhou_agari(&log.to_string()).unwrap_err(),
+        "Tenhou log removes a tile the winner does not hold"
+    );
+}
@@ -0,0 +1,354 @@
+mod common;
+
+use common::{hand, south, tile, tiles};
+use riichi_mahjong_scoring_calculator::implements::game::AgariType;
+use riichi_mahjong_scoring_calculator::implements::hand::KanType;
+use riichi_mahjong_scoring_calculator::implements::input::OpenMeldInput;
+use riichi_mahjong_scoring_calculator::implements::yaku::Yaku;
+use riichi_mahjong_scoring_calculator::implements::{
+    calculate_agari, organize_hand, organize_hand_with_meta,
+};
+
+// dora indicator counts are checked only when indicators are given
+
+#[test]
+fn default_context_without_indicators_scores() {
+    let mut input = hand("123m456p789s11z999m", "3m", AgariType::Tsumo);
+    input.player_context.is_riichi = true;
+    assert!(calculate_agari(&input).is_ok());
+}
+
+#[test]
+fn closed_kan_needs_a_kan_dora_indicator() {
+    let mut input = hand("123m456p789s11z", "3m", AgariType::Tsumo);
+    input.closed_kans = vec![tile("9m")];
+    input.player_context.is_riichi = true;
+
+    input.game_context.dora_indicators = tiles("2p");
+    assert_eq!(
+        calculate_agari(&input).unwrap_err(),
+        "Dora indicator count must be 1 + number of kans"
+    );
+
+    input.game_context.dora_indicators = tiles("2p5s");
+    assert!(calculate_agari(&input).is_ok());
+}
+
+#[test]
+fn riichi_without_ura_indicators_scores() {
+    let mut input = hand("123m456p789s11z999m", "3m", AgariType::Tsumo);
+    input.player_context.is_riichi = true;
+    input.game_context.dora_indicators = tiles("2p");
+    assert!(calculate_agari(&input).is_ok());
+}
+
+#[test]
+fn riichi_ura_count_must_match_dora_count() {
+    let mut input = hand("123m456p789s11z999m", "3m", AgariType::Tsumo);
+    input.player_context.is_riichi = true;
+    input.game_context.dora_indicators = tiles("2p");
+    input.game_context.uradora_indicators = tiles("3s4s");
+    assert_eq!(
+        calculate_agari(&input).unwrap_err(),
+        "Ura dora indicator count must match dora indicator count"
+    );
+}
+
+#[test]
+fn lenient_dora_count_accepts_odd_counts() {
+    let mut input = hand("123m456p789s11z999m", "3m", AgariType::Tsumo);
+    input.player_context.is_riichi = true;
+    input.game_context.dora_indicators = tiles("2p3p");
+    input.game_context.rules.lenient_dora_count = true;
+    assert!(calculate_agari(&input).is_ok());
+}
+
+// a tsumo whose winning tile is not in hand_tiles is an error, not a panic
+
+#[test]
+fn tsumo_tile_missing_from_hand_is_rejected() {
+    let mut input = hand("123m456p789s11z999m", "3m", AgariType::Tsumo);
+    input.winning_tile = tile("5s");
+    assert_eq!(
+        organize_hand(&input).unwrap_err(),
+        "Winning tile not in hand"
+    );
+    assert!(calculate_agari(&input).is_err());
+}
+
+// the winner's own kan breaks ippatsu
+
+#[test]
+fn ippatsu_with_rinshan_is_rejected() {
+    let mut input = hand("123m456s789s99m", "9m", AgariType::Tsumo);
+    input.closed_kans = tiles("3p");
+    input.player_context.is_riichi = true;
+    input.game_context.is_rinshan = true;
+    assert!(calculate_agari(&input).is_ok());
+
+    input.player_context.is_ippatsu = true;
+    assert_eq!(
+        calculate_agari(&input).unwrap_err(),
+        "Ippatsu cannot be combined with Rinshan"
+    );
+}
+
+#[test]
+fn ippatsu_without_riichi_is_rejected() {
+    let mut input = hand("123m456p789s11z999m", "3m", AgariType::Tsumo);
+    input.player_context.is_ippatsu = true;
+    assert_eq!(
+        calculate_agari(&input).unwrap_err(),
+        "Ippatsu requires Riichi"
+    );
+}
+
+// Tenhou and Chiihou need the first draw of a closed, uncalled hand
+
+#[test]
+fn tenhou_scores_for_the_dealer() {
+    let mut input = hand("123m456p789s11z999m", "3m", AgariType::Tsumo);
+    input.game_context.is_tenhou = true;
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Tenhou]);
+    assert_eq!(result.summary(), (13, 0, 48000));
+}
+
+#[test]
+fn tenhou_with_an_open_hand_is_rejected() {
+    let mut input = hand("123m456p789s11z999m", "3m", AgariType::Tsumo);
+    input.game_context.is_tenhou = true;
+    input.player_context.is_menzen = false;
+    assert_eq!(
+        calculate_agari(&input).unwrap_err(),
+        "Tenhou and Chiihou require a closed hand with no calls"
+    );
+}
+
+#[test]
+fn chiihou_on_a_kan_draw_is_rejected() {
+    let mut input = south(hand("123m456s789s99m", "9m", AgariType::Tsumo));
+    input.closed_kans = tiles("3p");
+    input.game_context.is_chiihou = true;
+    input.game_context.is_rinshan = true;
+    assert_eq!(
+        calculate_agari(&input).unwrap_err(),
+        "Tenhou and Chiihou cannot be won on a kan replacement draw"
+    );
+}
+
+#[test]
+fn tenhou_and_chiihou_seats() {
+    let mut input = south(hand("123m456p789s11z999m", "3m", AgariType::Tsumo));
+    input.game_context.is_tenhou = true;
+    assert_eq!(
+        calculate_agari(&input).unwrap_err(),
+        "Tenhou requires the dealer"
+    );
+
+    let mut input = hand("123m456p789s11z999m", "3m", AgariType::Tsumo);
+    input.game_context.is_chiihou = true;
+    assert_eq!(
+        calculate_agari(&input).unwrap_err(),
+        "Chiihou requires a non-dealer"
+    );
+}
+
+// aka dora are bounded by the fives in hand
+
+#[test]
+fn aka_dora_without_fives_is_rejected() {
+    let mut input = hand("123m346p789s11z999m", "3m", AgariType::Tsumo);
+    input.game_context.num_akadora = 1;
+    assert_eq!(
+        calculate_agari(&input).unwrap_err(),
+        "Aka dora count exceeds the number of fives in hand"
+    );
+}
+
+#[test]
+fn two_red_five_pin() {
+    let mut input = hand("123m345p55p789s999m", "3m", AgariType::Tsumo);
+    input.game_context.num_akadora = 2;
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(
+        result.yaku_list,
+        vec![Yaku::MenzenTsumo, Yaku::AkaDora, Yaku::AkaDora]
+    );
+    assert_eq!(result.summary(), (3, 40, 7800));
+}
+
+// open and closed kans both count, and set the dora indicator count
+
+#[test]
+fn two_kans_need_three_dora_indicators() {
+    let mut input = common::with_melds(
+        hand("123m789s99m", "9m", AgariType::Tsumo),
+        vec![OpenMeldInput::kan(tile("5s"), KanType::Daiminkan)],
+    );
+    input.closed_kans = tiles("3p");
+    assert_eq!(organize_hand_with_meta(&input).unwrap().1.kan_count, 2);
+    input.game_context.is_rinshan = true;
+
+    input.game_context.dora_indicators = tiles("1z2z");
+    assert_eq!(
+        calculate_agari(&input).unwrap_err(),
+        "Dora indicator count must be 1 + number of kans"
+    );
+
+    input.game_context.dora_indicators = tiles("1z2z3z");
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::RinshanKaihou]);
+}
+
+// a lenient count reads one ura indicator per dora indicator
+
+#[test]
+fn lenient_extra_ura_indicators_are_not_counted() {
+    let mut input = hand("123m456p789s11z999m", "3m", AgariType::Tsumo);
+    input.player_context.is_riichi = true;
+    input.game_context.rules.lenient_dora_count = true;
+    input.game_context.dora_indicators = tiles("1z");
+    input.game_context.uradora_indicators = tiles("8m8m8m");
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.dora_breakdown.ura, 3);
+    assert_eq!(
+        result.yaku_list,
+        vec![
+            Yaku::Riichi,
+            Yaku::MenzenTsumo,
+            Yaku::UraDora,
+            Yaku::UraDora,
+            Yaku::UraDora
+        ]
+    );
+    assert_eq!(result.summary(), (5, 40, 12000));
+}
+
+// every declared meld, kans included, takes 3 of the 14 concealed tiles
+
+const HAND_SIZE: &str = "Hand must hold 14 tiles less 3 per declared meld (winning tile included)";
+
+#[test]
+fn hand_size_with_one_to_four_closed_kans() {
+    let kans = ["2m", "3p", "4s", "6m"];
+    let groups = ["345m", "567p", "678s", "234p"];
+    for k in 1..=4 {
+        let concealed = groups[..4 - k].concat() + "88p";
+        let mut input = hand(&concealed, "8p", AgariType::Tsumo);
+        input.closed_kans = kans[..k].iter().map(|t| tile(t)).collect();
+        input.game_context.dora_indicators = tiles("1z2z3z4z5z")[..=k].to_vec();
+        assert_eq!(organize_hand_with_meta(&input).unwrap().1.kan_count, k);
+        assert!(calculate_agari(&input).is_ok(), "{k} kans");
+
+        input.hand_tiles.push(tile("5s"));
+        assert_eq!(calculate_agari(&input).unwrap_err(), HAND_SIZE, "{k} kans");
+        input.hand_tiles.truncate(input.hand_tiles.len() - 2);
+        assert_eq!(calculate_agari(&input).unwrap_err(), HAND_SIZE, "{k} kans");
+    }
+}
+
+#[test]
+fn open_meld_tiles_stay_out_of_the_hand() {
+    let mut input = common::with_melds(
+        south(hand("345m567p678s88p", "8p", AgariType::Ron)),
+        vec![OpenMeldInput::pon(tile("2m"))],
+    );
+    assert!(calculate_agari(&input).is_ok());
+    input.hand_tiles.extend(tiles("222m"));
+    assert_eq!(calculate_agari(&input).unwrap_err(), HAND_SIZE);
+}
+
+// a closed kan takes all four copies of its tile
+
+#[test]
+fn closed_kan_declared_twice() {
+    let mut input = hand("456p789s99m", "9m", AgariType::Tsumo);
+    input.closed_kans = tiles("2m2m");
+    assert_eq!(
+        calculate_agari(&input).unwrap_err(),
+        "The same tile is declared as a closed kan twice"
+    );
+}
+
+#[test]
+fn closed_kan_tile_in_an_open_meld() {
+    for meld in [
+        OpenMeldInput::pon(tile("2m")),
+        OpenMeldInput::chi(tile("1m")),
+    ] {
+        let mut input = common::with_melds(hand("456p789s99m", "9m", AgariType::Tsumo), vec![meld]);
+        input.closed_kans = tiles("2m");
+        assert_eq!(
+            calculate_agari(&input).unwrap_err(),
+            "A closed kan tile is also part of an open meld"
+        );
+    }
+}
+
+// Chankan is a ron on a tile whose other three copies sit in the robbed pon
+
+#[test]
+fn chankan_on_a_ryanmen() {
+    let mut input = south(hand("123m456p789s234s99s", "2s", AgariType::Ron));
+    input.game_context.is_chankan = true;
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Chankan, Yaku::Pinfu]);
+    assert_eq!(result.summary(), (2, 30, 2000));
+}
+
+#[test]
+fn chankan_tile_already_held() {
+    let mut input = south(hand("123m456p789s345s55s", "5s", AgariType::Ron));
+    input.game_context.is_chankan = true;
+    assert_eq!(
+        calculate_agari(&input).unwrap_err(),
+        "Chankan tile cannot also be in the winner's hand or melds"
+    );
+}
+
+#[test]
+fn chankan_on_tsumo() {
+    let mut input = south(hand("123m456p789s234s99s", "2s", AgariType::Tsumo));
+    input.game_context.is_chankan = true;
+    assert_eq!(
+        calculate_agari(&input).unwrap_err(),
+        "Chankan requires a Ron on the added kan tile"
+    );
+}
+
+#[test]
+fn dragon_pair_matching_a_pon_is_five_copies() {
+    let input = common::with_melds(
+        south(hand("234m567p55z", "2m", AgariType::Ron)),
+        vec![
+            OpenMeldInput::pon(tile("5z")),
+            OpenMeldInput::pon(tile("6z")),
+        ],
+    );
+    assert_eq!(
+        calculate_agari(&input).unwrap_err(),
+        "More than 4 copies of a tile"
+    );
+}
+
+#[test]
+fn two_dragon_pons_and_the_third_as_pair() {
+    let input = common::with_melds(
+        south(hand("234m567p77z", "2m", AgariType::Ron)),
+        vec![
+            OpenMeldInput::pon(tile("5z")),
+            OpenMeldInput::pon(tile("6z")),
+        ],
+    );
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(
+        result.yaku_list,
+        vec![
+            Yaku::YakuhaiSangenpai,
+            Yaku::YakuhaiSangenpai,
+            Yaku::Shousangen
+        ]
+    );
+    assert_eq!(result.summary(), (4, 30, 7700));
+}
@@ -0,0 +1,35 @@
+// runs natively through `unsupported = test`, and in a browser with wasm-pack test
+#![cfg(feature = "wasm")]
+
+mod common;
+
+use common::hand;
+use riichi_mahjong_scoring_calculator::implements::game::AgariType;
+use riichi_mahjong_scoring_calculator::implements::scoring::AgariResult;
+use riichi_mahjong_scoring_calculator::implements::yaku::Yaku;
+use riichi_mahjong_scoring_calculator::wasm::score_hand_json;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test(unsupported = test)]
+fn scores_a_hand_from_json() {
+    let mut input = hand("123m456p789s11z999m", "9m", AgariType::Tsumo);
+    input.player_context.is_riichi = true;
+    let json = score_hand_json(&serde_json::to_string(&input).unwrap());
+
+    let result: AgariResult = serde_json::from_str(&json).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Riichi, Yaku::MenzenTsumo]);
+    assert_eq!(result.summary(), (2, 40, 3900));
+}
+
+#[wasm_bindgen_test(unsupported = test)]
+fn reports_errors_as_json() {
+    let input = hand("123m456p789s11z999m", "9m", AgariType::Ron);
+    let json = score_hand_json(&serde_json::to_string(&input).unwrap());
+    assert_eq!(
+        json,
+        r#"{"error":"Complete hand, but no yaku (dora alone cannot win)"}"#
+    );
+
+    let json = score_hand_json("not json");
+    assert!(json.starts_with(r#"{"error":"#), "{json}");
+}
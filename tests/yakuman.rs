@@ -0,0 +1,383 @@
+mod common;
+
+use common::{hand, south, tile, tiles, with_melds};
+use riichi_mahjong_scoring_calculator::implements::fu::calculate_fu;
+use riichi_mahjong_scoring_calculator::implements::game::AgariType;
+use riichi_mahjong_scoring_calculator::implements::hand::{KanType, Machi};
+use riichi_mahjong_scoring_calculator::implements::input::{OpenMeldInput, UserInput};
+use riichi_mahjong_scoring_calculator::implements::scoring::HandLimit;
+use riichi_mahjong_scoring_calculator::implements::yaku::Yaku;
+use riichi_mahjong_scoring_calculator::implements::{NOT_A_WINNING_HAND, calculate_agari};
+
+// green Chiitoitsu is Ryuuiisou, reachable only with chiitoitsu_allow_four
+
+#[test]
+fn green_chiitoitsu_with_allow_four_is_ryuuiisou() {
+    let mut input = south(hand("222233446688s66z", "6z", AgariType::Ron));
+    input.game_context.rules.chiitoitsu_allow_four = true;
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Ryuuiisou]);
+    assert_eq!(result.limit_name, Some(HandLimit::Yakuman));
+    assert_eq!(result.summary(), (13, 0, 32000));
+}
+
+#[test]
+fn green_chiitoitsu_without_allow_four_is_not_a_winning_hand() {
+    let input = south(hand("222233446688s66z", "6z", AgariType::Ron));
+    assert_eq!(calculate_agari(&input).unwrap_err(), NOT_A_WINNING_HAND);
+}
+
+// Daisharin is a yakuman only under RuleConfig::local_yakuman
+
+#[test]
+fn daisharin_with_local_yakuman() {
+    let mut input = south(hand("22334455667788p", "8p", AgariType::Ron));
+    input.game_context.rules.local_yakuman = true;
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Daisharin]);
+    assert_eq!(result.summary(), (13, 0, 32000));
+}
+
+#[test]
+fn daisharin_without_local_yakuman_scores_its_regular_yaku() {
+    let input = south(hand("22334455667788p", "8p", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(
+        result.yaku_list,
+        vec![Yaku::Pinfu, Yaku::Tanyao, Yaku::Ryanpeikou, Yaku::Chinitsu]
+    );
+    assert_eq!(result.limit_name, Some(HandLimit::Sanbaiman));
+    assert_eq!(result.summary(), (11, 30, 24000));
+}
+
+#[test]
+fn daichikurin_and_daisuurin() {
+    let mut input = south(hand("22334455667788s", "8s", AgariType::Ron));
+    input.game_context.rules.local_yakuman = true;
+    assert_eq!(
+        calculate_agari(&input).unwrap().yaku_list,
+        vec![Yaku::Daichikurin]
+    );
+
+    let mut input = south(hand("22334455667788m", "8m", AgariType::Ron));
+    input.game_context.rules.local_yakuman = true;
+    assert_eq!(
+        calculate_agari(&input).unwrap().yaku_list,
+        vec![Yaku::Daisuurin]
+    );
+}
+
+// Ryuuiisou with or without Hatsu, any non-green tile breaks it
+// (green Chiitoitsu is covered by the chiitoitsu_allow_four tests above)
+
+#[test]
+fn ryuuiisou_with_hatsu() {
+    let input = south(hand("223344666888s66z", "6z", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Ryuuiisou]);
+    assert_eq!(result.summary(), (13, 0, 32000));
+}
+
+#[test]
+fn ryuuiisou_without_hatsu() {
+    let input = south(hand("22334444666888s", "8s", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Ryuuiisou]);
+    assert_eq!(result.summary(), (13, 0, 32000));
+}
+
+#[test]
+fn five_or_one_sou_is_not_green() {
+    let cases = [
+        (
+            "22334455666888s",
+            vec![Yaku::Tanyao, Yaku::Iipeikou, Yaku::Chinitsu],
+            (8, 40, 16000),
+        ),
+        (
+            "22334411666888s",
+            vec![Yaku::Iipeikou, Yaku::Chinitsu],
+            (7, 40, 12000),
+        ),
+    ];
+    for (notation, yaku_list, summary) in cases {
+        let input = south(hand(notation, "8s", AgariType::Ron));
+        let result = calculate_agari(&input).unwrap();
+        assert_eq!(result.yaku_list, yaku_list, "{notation}");
+        assert_eq!(result.summary(), summary, "{notation}");
+    }
+}
+
+// four kans leave a tanki on the pair
+
+#[test]
+fn suukantsu_with_open_kans() {
+    let input = with_melds(
+        south(hand("99s", "9s", AgariType::Tsumo)),
+        ["2m", "3p", "4s", "6m"]
+            .map(|t| OpenMeldInput::kan(tile(t), KanType::Daiminkan))
+            .to_vec(),
+    );
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(common::machi(&result), Machi::Tanki);
+    assert_eq!(result.yaku_list, vec![Yaku::Suukantsu]);
+    assert_eq!(result.summary(), (13, 0, 32000));
+}
+
+#[test]
+fn suukantsu_with_closed_terminal_kans_and_its_fu() {
+    let mut input = south(hand("99s", "9s", AgariType::Tsumo));
+    input.closed_kans = tiles("1m9p1s1z");
+    let result = calculate_agari(&input).unwrap();
+    // closed kans are also four concealed triplets, the tanki one counts double
+    assert_eq!(result.yaku_list, vec![Yaku::Suukantsu, Yaku::SuuankouTanki]);
+    assert_eq!(result.summary(), (39, 0, 96000));
+
+    // 20 base + 2 tsumo + 4 x 32 closed terminal kans + 2 tanki
+    let fu = calculate_fu(
+        &result.hand_structure,
+        &result.yaku_list,
+        &input.player_context,
+        &input.game_context,
+        AgariType::Tsumo,
+    );
+    assert_eq!(fu, 160);
+}
+
+// Chuuren against plain Chinitsu
+
+#[test]
+fn junsei_chuuren_is_a_double_yakuman() {
+    let input = south(hand("11123455678999m", "5m", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::JunseiChuurenPoutou]);
+    assert_eq!(result.summary(), (26, 0, 64000));
+}
+
+#[test]
+fn chuuren_on_a_single_wait() {
+    let input = south(hand("11123455678999m", "9m", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::ChuurenPoutou]);
+    assert_eq!(result.summary(), (13, 0, 32000));
+}
+
+#[test]
+fn one_to_nine_flush_with_two_ones_is_only_chinitsu() {
+    let input = south(hand("11222345678999m", "4m", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Chinitsu]);
+    assert_eq!(result.summary(), (6, 50, 12000));
+}
+
+#[test]
+fn open_chinitsu_is_five_han() {
+    let input = with_melds(
+        south(hand("45667811199m", "6m", AgariType::Ron)),
+        vec![OpenMeldInput::chi(tile("2m"))],
+    );
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Chinitsu]);
+    assert_eq!(result.summary(), (5, 30, 8000));
+}
+
+// Kokushi ignores dora, the 13-sided wait replaces the plain yaku
+
+#[test]
+fn kokushi_single_wait_ignores_dora() {
+    let mut input = south(hand("19m19p19s12345677z", "1z", AgariType::Ron));
+    input.game_context.dora_indicators = tiles("9m");
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::KokushiMusou]);
+    assert_eq!(result.dora_breakdown.dora, 0);
+    assert_eq!(result.summary(), (13, 0, 32000));
+}
+
+#[test]
+fn kokushi_thirteen_sided_wait() {
+    let mut input = south(hand("119m19p19s1234567z", "1m", AgariType::Ron));
+    input.game_context.dora_indicators = tiles("9m");
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::KokushiMusouJusanmen]);
+    assert_eq!(common::machi(&result), Machi::KokushiJusanmen);
+    assert_eq!(result.summary(), (26, 0, 64000));
+}
+
+// a closed kan breaks the nine gates even on a Chuuren-shaped flush
+
+#[test]
+fn closed_kan_flush_is_not_chuuren() {
+    let mut input = south(hand("23456778999m", "8m", AgariType::Ron));
+    input.closed_kans = tiles("1m");
+    input.game_context.dora_indicators = tiles("1z2z");
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Chinitsu]);
+    assert_eq!(result.summary(), (6, 70, 12000));
+}
+
+// one hand per yakuman not covered above
+
+#[test]
+fn daisangen() {
+    let input = south(hand("555z666z777z234m55p", "4m", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Daisangen]);
+    assert_eq!(result.summary(), (13, 0, 32000));
+}
+
+#[test]
+fn suuankou_on_a_shanpon_tsumo() {
+    let input = south(hand("111m444p777s999m55z", "9m", AgariType::Tsumo));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Suuankou]);
+    assert_eq!(result.summary(), (13, 0, 32000));
+}
+
+#[test]
+fn suuankou_tanki_on_ron() {
+    let input = south(hand("111m444p777s999m55z", "5z", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::SuuankouTanki]);
+    assert_eq!(result.summary(), (26, 0, 64000));
+}
+
+#[test]
+fn daisuushi() {
+    let input = with_melds(
+        south(hand("111z222z333z55m", "3z", AgariType::Ron)),
+        vec![OpenMeldInput::pon(tile("4z"))],
+    );
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Daisuushi]);
+    assert_eq!(result.summary(), (13, 0, 32000));
+}
+
+#[test]
+fn shousuushi() {
+    let input = with_melds(
+        south(hand("111z222z33z555m", "5m", AgariType::Ron)),
+        vec![OpenMeldInput::pon(tile("4z"))],
+    );
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Shousuushi]);
+    assert_eq!(result.summary(), (13, 0, 32000));
+}
+
+#[test]
+fn tsuuiisou() {
+    let input = with_melds(
+        south(hand("111z222z333z55z", "5z", AgariType::Ron)),
+        vec![OpenMeldInput::pon(tile("7z"))],
+    );
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Tsuuiisou]);
+    assert_eq!(result.summary(), (13, 0, 32000));
+}
+
+#[test]
+fn chinroutou() {
+    let input = with_melds(
+        south(hand("999m111p999s99p", "9s", AgariType::Ron)),
+        vec![OpenMeldInput::pon(tile("1m"))],
+    );
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Chinroutou]);
+    assert_eq!(result.summary(), (13, 0, 32000));
+}
+
+// Chinroutou stacks with Suuankou
+
+#[test]
+fn chinroutou_suuankou_tsumo() {
+    let input = south(hand("111m999m111p999s99p", "9s", AgariType::Tsumo));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Chinroutou, Yaku::Suuankou]);
+    assert_eq!(result.summary(), (26, 0, 64000));
+}
+
+#[test]
+fn chinroutou_suuankou_tanki() {
+    let input = south(hand("111m999m111p999s99p", "9p", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(
+        result.yaku_list,
+        vec![Yaku::Chinroutou, Yaku::SuuankouTanki]
+    );
+    assert_eq!(result.summary(), (39, 0, 96000));
+}
+
+// three kans are Sankantsu with their fu, a fourth escalates to Suukantsu
+
+fn three_kans(concealed: &str) -> UserInput {
+    let mut input = with_melds(
+        south(hand(concealed, "8p", AgariType::Tsumo)),
+        vec![OpenMeldInput::kan(tile("2m"), KanType::Daiminkan)],
+    );
+    input.closed_kans = tiles("3p4s");
+    input.game_context.dora_indicators = tiles("1z2z3z4z");
+    input
+}
+
+#[test]
+fn sankantsu_open_and_closed() {
+    let result = calculate_agari(&three_kans("678s88p")).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Tanyao, Yaku::Sankantsu]);
+    // 20 + 2 tsumo + 8 open simple kan + 2 x 16 closed simple kans + 2 tanki = 64
+    assert_eq!(result.summary(), (3, 70, 8000));
+}
+
+#[test]
+fn fourth_kan_is_suukantsu() {
+    let mut input = three_kans("88p");
+    input.closed_kans.push(tile("6s"));
+    input.game_context.dora_indicators = tiles("1z2z3z4z5z");
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Suukantsu]);
+    assert_eq!(result.summary(), (13, 0, 32000));
+}
+
+// Suuankou by wait and agari type (shanpon tsumo is covered above)
+
+#[test]
+fn suuankou_tanki_on_tsumo() {
+    let input = south(hand("111m444p777s999m55z", "5z", AgariType::Tsumo));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::SuuankouTanki]);
+    assert_eq!(result.summary(), (26, 0, 64000));
+}
+
+#[test]
+fn shanpon_ron_is_sanankou_toitoi() {
+    let input = south(hand("111m444p777s999m55z", "9m", AgariType::Ron));
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(result.yaku_list, vec![Yaku::Toitoi, Yaku::Sanankou]);
+    assert_eq!(result.summary(), (4, 60, 8000));
+}
+
+// max_yakuman caps the stacked multiple, the yaku list stays whole
+
+fn triple_stack() -> UserInput {
+    south(hand("555z666z777z111z22z", "2z", AgariType::Ron))
+}
+
+#[test]
+fn stacked_yakuman_uncapped() {
+    let result = calculate_agari(&triple_stack()).unwrap();
+    assert_eq!(
+        result.yaku_list,
+        vec![Yaku::Tsuuiisou, Yaku::SuuankouTanki, Yaku::Daisangen]
+    );
+    assert_eq!(result.summary(), (52, 0, 128000));
+}
+
+#[test]
+fn stacked_yakuman_capped_at_one() {
+    let mut input = triple_stack();
+    input.game_context.rules.max_yakuman = Some(1);
+    let result = calculate_agari(&input).unwrap();
+    assert_eq!(
+        result.yaku_list,
+        vec![Yaku::Tsuuiisou, Yaku::SuuankouTanki, Yaku::Daisangen]
+    );
+    assert_eq!(result.summary(), (13, 0, 32000));
+}